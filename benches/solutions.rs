@@ -0,0 +1,42 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc_2023::Solution;
+use aoc_2023::days::*;
+
+// Benchmarks parsing and each part on the real puzzle input. Days without an
+// input file are skipped. Some parts take minutes per iteration (day 5 part 2
+// in particular), so filter to the days of interest, e.g.
+// `cargo bench -- day14`.
+fn bench_day<S: Solution>(c: &mut Criterion, number: u32) {
+    let path = format!("./input/{number:02}");
+    let Ok(input) = std::fs::read_to_string(&path) else {return};
+    let parsed = S::parse(&input)
+        .unwrap_or_else(|e| panic!("{path}: {e}"));
+
+    let mut group = c.benchmark_group(format!("day{number:02}"));
+    group.sample_size(10);
+    group.bench_function("parse", |b| b.iter(|| S::parse(&input)));
+    group.bench_function("part1", |b| b.iter(|| S::part1(&parsed)));
+    group.bench_function("part2", |b| b.iter(|| S::part2(&parsed)));
+    group.finish();
+}
+
+fn solutions(c: &mut Criterion) {
+    bench_day::<day01::Day01>(c, 1);
+    bench_day::<day02::Day02>(c, 2);
+    bench_day::<day03::Day03>(c, 3);
+    bench_day::<day04::Day04>(c, 4);
+    bench_day::<day05::Day05>(c, 5);
+    bench_day::<day06::Day06>(c, 6);
+    bench_day::<day07::Day07>(c, 7);
+    bench_day::<day08::Day08>(c, 8);
+    bench_day::<day09::Day09>(c, 9);
+    bench_day::<day10::Day10>(c, 10);
+    bench_day::<day11::Day11>(c, 11);
+    bench_day::<day12::Day12>(c, 12);
+    bench_day::<day13::Day13>(c, 13);
+    bench_day::<day14::Day14>(c, 14);
+}
+
+criterion_group!(benches, solutions);
+criterion_main!(benches);
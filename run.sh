@@ -1,5 +1,5 @@
 #!/bin/sh
-RUST_BACKTRACE=1 cargo test --bin $1 --release
+RUST_BACKTRACE=1 cargo test day$1 --release
 if [ $? -eq 0 ]; then
-    RUST_BACKTRACE=1 cargo run --bin $1 --release ./input/$1
+    RUST_BACKTRACE=1 cargo run --bin aoc --release -- run $1 --check
 fi
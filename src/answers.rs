@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use crate::ParseError;
+use crate::parse::Scanner;
+
+// Accepted answers keyed by day and part, stored as a small subset of TOML:
+//
+//     [day07]
+//     part1 = "6440"
+//     part2 = "5905"
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u32, u32), String>,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Answers, ParseError> {
+        let mut answers = BTreeMap::new();
+        let mut day = None;
+
+        for (idx, l) in input.lines().enumerate() {
+            let mut scanner = Scanner::new(idx + 1, l);
+            scanner.skip_whitespace();
+            match scanner.peek() {
+                None | Some('#') => continue,
+                Some('[') => {
+                    scanner.literal("[day")?;
+                    day = Some(scanner.number::<u32>()?);
+                    scanner.literal("]")?;
+                },
+                _ => {
+                    let column = scanner.column();
+                    scanner.literal("part")?;
+                    let part = scanner.number::<u32>()?;
+                    scanner.skip_whitespace();
+                    scanner.literal("=")?;
+                    scanner.skip_whitespace();
+                    scanner.literal("\"")?;
+                    let answer = scanner.take_while(|c| c != '"');
+                    scanner.literal("\"")?;
+
+                    let day = day.ok_or_else(|| scanner.invalid(
+                        column,
+                        "answer given before any [dayNN] table".to_string()
+                    ))?;
+                    answers.insert((day, part), answer.to_string());
+                },
+            }
+            scanner.finish()?;
+        }
+
+        Ok(Answers {answers})
+    }
+
+    // A missing ledger is treated as one with nothing recorded yet.
+    pub fn load(path: &str) -> Result<Answers, String> {
+        match std::fs::read_to_string(path) {
+            Ok(input) => Answers::parse(&input).map_err(|e| format!("{path}: {e}")),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                Ok(Answers::default())
+            },
+            Err(e) => Err(format!("Failed to read {path}: {e}")),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        std::fs::write(path, self.to_string())
+            .map_err(|e| format!("Failed to write {path}: {e}"))
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|a| a.as_str())
+    }
+
+    pub fn record(&mut self, day: u32, part: u32, answer: String) {
+        self.answers.insert((day, part), answer);
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut prev_day = None;
+        for ((day, part), answer) in &self.answers {
+            if prev_day != Some(day) {
+                if prev_day.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[day{day:02}]")?;
+                prev_day = Some(day);
+            }
+            writeln!(f, "part{part} = \"{answer}\"")?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn round_trip() {
+    let input = "[day01]\npart1 = \"142\"\npart2 = \"281\"\n\n[day07]\npart1 = \"6440\"\n";
+    let answers = Answers::parse(input).unwrap();
+    assert_eq!(answers.get(1, 2), Some("281"));
+    assert_eq!(answers.get(7, 2), None);
+    assert_eq!(answers.to_string(), input);
+
+    assert!(Answers::parse("part1 = \"142\"").is_err());
+    assert!(Answers::parse("[day01]\npart1 = 142").is_err());
+}
@@ -1,48 +1,91 @@
-use std::cmp::min;
+use aoc_2023::{cli, days, Solution};
+use aoc_2023::days::day01::{self, Day01, Vocabulary};
 
-fn main() {
-  let args: Vec<String> = std::env::args().collect();
-  if args.len() < 2 {panic!("Path must be input as arg.");}
-
-  let numbers = [
-    ("one".as_bytes(),   b'1'),
-    ("two".as_bytes(),   b'2'),
-    ("three".as_bytes(), b'3'),
-    ("four".as_bytes(),  b'4'),
-    ("five".as_bytes(),  b'5'),
-    ("six".as_bytes(),   b'6'),
-    ("seven".as_bytes(), b'7'),
-    ("eight".as_bytes(), b'8'),
-    ("nine".as_bytes(),  b'9'),
-  ].into_iter();
-
-  let solution: u64 = unsafe {
-     std::fs::read_to_string(args[1].clone())
-      .expect("Failed to read input file.")
-      .lines()
-      .map(|l| {
-        let mut line = l.to_string();
-        let bytes = line.as_bytes_mut();
-
-        if l.len() >= 3 {
-          for i in (0..l.len()-2).into_iter() {
-            let slice = &mut bytes[i..min(i+5,l.len())];
-            for (name, num) in numbers.clone() {
-              if slice.starts_with(name)  {
-                slice[0] = num;
-              }
-            }
-          }
+// Prints how every line of each input is read, failing if any has no digit.
+fn explain(usage: &str, args: &[String], words: &Vocabulary) -> Result<(), String> {
+    let mut errors = 0;
+    cli::run_report(usage, args, |text| {
+        let document = day01::parse_with(text, words.clone()).map_err(|e| e.to_string())?;
+        let (report, flagged) = day01::explain(&document);
+        errors += flagged;
+        Ok(report)
+    })?;
+
+    match errors {
+        0 => Ok(()),
+        n => Err(format!("{n} line reading(s) found no digit.")),
+    }
+}
+
+// Sums each input without holding it in memory.
+fn stream(
+    args: &[String],
+    words: &Vocabulary,
+    chunk_size: usize,
+    parallel: bool
+) -> Result<(), String> {
+    let paths = match args.is_empty() {
+        true  => vec!["-".to_string()],
+        false => args.to_vec(),
+    };
+    let labelled = paths.len() > 1;
+
+    for path in paths {
+        let reader = cli::open_input(&path)?;
+        let (part1, part2) = day01::stream_sums(reader, words, chunk_size, parallel)
+            .map_err(|e| format!("{path}: {e}"))?;
+        match labelled {
+            true  => println!("{path} part 1: {part1}\n{path} part 2: {part2}"),
+            false => println!("{part1}\n{part2}"),
         }
-        
-        let mut iter = line.chars().filter(|c| c >= &'0' && c <= &'9');
-        let first = iter.next().unwrap();
-        let last = match iter.last() {Some(d) => d, None => first};
-        return 10*(first as u64 - '0' as u64) + (last as u64 - '0' as u64)
-      })
-      .sum()
-  };
+    }
+    Ok(())
+}
+
+fn run(program: &str, mut args: Vec<String>) -> Result<(), String> {
+    let usage = format!(
+        "Usage: {program} [--vocabulary <{}|PATH>] {}\n       \
+         {program} --explain [--vocabulary <{0}|PATH>] [PATH|-]...\n       \
+         {program} --stream [--chunk-size <BYTES>] [--parallel] \
+         [--vocabulary <{0}|PATH>] [PATH|-]...",
+        day01::BUILTINS.join("|"),
+        cli::OPTIONS
+    );
 
+    let words = cli::take_option(&mut args, "--vocabulary")?;
+    let words = Vocabulary::load(words.as_deref().unwrap_or("english"))?;
+    let explain_mode = cli::take_flag(&mut args, "--explain");
+    let stream_mode = cli::take_flag(&mut args, "--stream");
+    let parallel = cli::take_flag(&mut args, "--parallel");
+    let chunk_size = cli::take_option(&mut args, "--chunk-size")?
+        .map(|size| size.parse::<usize>().ok()
+            .filter(|&s| s > 0)
+            .ok_or(format!("Invalid chunk size: {size}")))
+        .transpose()?;
+    if !stream_mode && (parallel || chunk_size.is_some()) {
+        return Err(format!("--parallel and --chunk-size need --stream.\n{usage}"));
+    }
 
-  println!("{solution}")
+    match (explain_mode, stream_mode) {
+        (true, true) => Err(format!("--explain and --stream cannot be combined.\n{usage}")),
+        (true, false) => explain(&usage, &args, &words),
+        (false, true) => {
+            cli::reject_options(&usage, &args)?;
+            stream(&args, &words, chunk_size.unwrap_or(1 << 20), parallel)
+        },
+        (false, false) => cli::run_with(1, &usage, &args, |text, parts| {
+            days::solve_with(
+                text,
+                parts,
+                |t| day01::parse_with(t, words.clone()),
+                Day01::part1,
+                Day01::part2
+            )
+        }),
+    }
+}
+
+fn main() {
+    let (program, args) = cli::args();
+    cli::exit_on_error(run(&program, args));
 }
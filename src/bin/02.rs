@@ -1,132 +1,68 @@
-use std::cmp::max;
+use aoc_2023::{cli, days, Solution};
+use aoc_2023::days::day02::{self, Day02, Game};
 
-#[derive(Debug, Clone, Copy)]
-enum Colour {
-  Red, Green, Blue
+// Prints `report` for the games in each input.
+fn report(usage: &str, args: &[String], report: impl Fn(&[Game]) -> String) -> Result<(), String> {
+    cli::run_report(usage, args, |text| {
+        let games = Day02::parse(text).map_err(|e| e.to_string())?;
+        Ok(report(&games))
+    })
 }
 
-#[derive(Debug, Default, Clone, Copy)]
-struct Set {
-  red: u32,
-  green: u32,
-  blue: u32,
-}
+const DEFAULT_MAX_CUBES: u32 = 100;
 
-impl Set {  
-  fn new(red: u32, green: u32, blue: u32) -> Self {
-    Set {red, green, blue}
-  }
+fn run(program: &str, mut args: Vec<String>) -> Result<(), String> {
+    let usage = format!(
+        "Usage: {program} [--bag <SPEC> | --bag-file <PATH>] {}\n       \
+         {program} --query [--bag <SPEC> | --bag-file <PATH>] [PATH|-]...\n       \
+         {program} --estimate [--max-cubes <N>] [PATH|-]...\n\n\
+         A bag SPEC is written like a draw, e.g. \"12 red, 13 green, 14 blue\".\n\
+         --estimate finds each game's most likely bag, with at most N cubes \
+         (default {DEFAULT_MAX_CUBES}).",
+        cli::OPTIONS
+    );
 
-  fn from(colour: Colour, value: u32) -> Self {
-    let (red, green, blue) = match colour {
-      Colour::Red   => (value,0,0),
-      Colour::Green => (0,value,0),
-      Colour::Blue  => (0,0,value)
-    };
-    Set {red, green, blue}
-  }
-
-  fn maximise(self, rhs: Self) -> Self {
-    Set {
-      red:   max(self.red,   rhs.red),
-      green: max(self.green, rhs.green),
-      blue:  max(self.blue,  rhs.blue)
+    let max_cubes = cli::take_option(&mut args, "--max-cubes")?
+        .map(|n| n.parse::<u32>().map_err(|e| format!("--max-cubes: {e}")))
+        .transpose()?;
+    if cli::take_flag(&mut args, "--estimate") {
+        let max_cubes = max_cubes.unwrap_or(DEFAULT_MAX_CUBES);
+        return report(&usage, &args, |games| day02::estimate_report(games, max_cubes));
     }
-  }
-
-  fn is_possible(self, maximum: Self) -> bool {
-    self.red   <= maximum.red   &&
-    self.green <= maximum.green &&
-    self.blue  <= maximum.blue 
-  }
-
-  fn power(self) -> u32 {
-    self.red * self.green * self.blue
-  }
-}
-
-impl std::ops::Add for Set {
-  type Output = Self;
-  fn add(self, rhs: Self) -> Self::Output {
-    Set {
-      red:   self.red   + rhs.red,
-      green: self.green + rhs.green,
-      blue:  self.blue  + rhs.blue
+    if max_cubes.is_some() {
+        return Err(format!("--max-cubes requires --estimate.\n{usage}"));
     }
-  }
-}
 
-impl std::iter::Sum for Set {
-  fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-    iter.fold(Self::default(), |acc, x| acc + x)
-  }
-}
-
-fn part_1(game_string: String, max_possible: Set) -> u32 {
-  game_string.lines()
-    .map(|l| {
-      let info = l.split(':').collect::<Vec<&str>>();
-      let id:u32 =
-       info[0].trim().split(' ').collect::<Vec<&str>>()[1].parse().unwrap();
-
-      let set_max = info[1].split(';')
-        .map(|s| {
-          s.split(',')
-            .map(|cubes| {
-              if cubes.contains("red") {
-                return Set::from(Colour::Red, cubes.trim().split(' ').collect::<Vec<&str>>()[0].parse().unwrap());
-              } else if cubes.contains("blue") {
-                return Set::from(Colour::Blue, cubes.trim().split(' ').collect::<Vec<&str>>()[0].parse().unwrap());
-              } else if cubes.contains("green") {
-                return Set::from(Colour::Green, cubes.trim().split(' ').collect::<Vec<&str>>()[0].parse().unwrap());
-              }
-              unreachable!();
-            }).sum::<Set>()
-        })
-        .fold(Set::default(), |acc, s| Set::maximise(acc,s));
-      (id, set_max)
-    })
-    .filter(|(_, set)| set.is_possible(max_possible))
-    .map(|(id,_)| id)
-    .sum()
-}
-
-fn part_2(game_string: String) -> u32 {
-  game_string.lines()
-    .map(|l| {
-      let info = l.split(':').collect::<Vec<&str>>();
-      // let id:u32 =
-      //  info[0].trim().split(' ').collect::<Vec<&str>>()[1].parse().unwrap();
+    let spec = cli::take_option(&mut args, "--bag")?;
+    let file = cli::take_option(&mut args, "--bag-file")?;
+    let bag = match (spec, file) {
+        (Some(_), Some(_)) => {
+            return Err(format!("Only one of --bag and --bag-file can be used.\n{usage}"));
+        },
+        (Some(spec), None) => day02::parse_bag(&spec)
+            .map_err(|e| format!("--bag: {e}"))?,
+        (None, Some(path)) => {
+            let input = cli::read_input(&path)?;
+            day02::parse_bag(&input.text).map_err(|e| format!("{path}: {e}"))?
+        },
+        (None, None) => day02::default_bag(),
+    };
 
-      let set_max = info[1].split(';')
-        .map(|s| {
-          s.split(',')
-            .map(|cubes| {
-              if cubes.contains("red") {
-                return Set::from(Colour::Red, cubes.trim().split(' ').collect::<Vec<&str>>()[0].parse().unwrap());
-              } else if cubes.contains("blue") {
-                return Set::from(Colour::Blue, cubes.trim().split(' ').collect::<Vec<&str>>()[0].parse().unwrap());
-              } else if cubes.contains("green") {
-                return Set::from(Colour::Green, cubes.trim().split(' ').collect::<Vec<&str>>()[0].parse().unwrap());
-              }
-              unreachable!();
-            }).sum::<Set>()
-        })
-        .fold(Set::default(), |acc, s| Set::maximise(acc,s));
-      set_max                    // Only this line go down differs from part 1
+    if cli::take_flag(&mut args, "--query") {
+        return report(&usage, &args, |games| day02::query(games, &bag));
+    }
+    cli::run_with(2, &usage, &args, |text, parts| {
+        days::solve_with(
+            text,
+            parts,
+            Day02::parse,
+            |games| Ok(day02::sum_possible_ids(games, &bag)),
+            Day02::part2
+        )
     })
-    .map(|set_max| set_max.power())
-    .sum()
 }
 
 fn main() {
-  let args: Vec<String> = std::env::args().collect();
-  if args.len() < 2 {panic!("Input file path must be input as arg 1.");}
-  let game_strings =
-    std::fs::read_to_string(args[1].clone()).expect("Failed to read input.");
-  let solution1: u32 = part_1(game_strings.clone(), Set::new(12, 13, 14));
-  let solution2: u32 = part_2(game_strings);
-
-  println!("{}", solution1);
-  println!("{}", solution2);
+    let (program, args) = cli::args();
+    cli::exit_on_error(run(&program, args));
 }
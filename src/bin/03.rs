@@ -1,126 +1,58 @@
-use std::cmp::{min, max};
-
-
-
-fn number_bounds(sym_rng_j: usize, sym_rng_i: usize, input_chars: &Vec<Vec<char>>) -> (usize, usize) {
-  let (mut digit_lf, mut digit_rt) = (sym_rng_j, sym_rng_j);
-
-  while digit_lf as isize - 1 >= 0 && input_chars[sym_rng_i][digit_lf-1].is_digit(10) {
-  digit_lf -= 1;
-                }
-  while digit_rt + 1 < input_chars[sym_rng_i].len() && input_chars[sym_rng_i][digit_rt+1].is_digit(10) {
-  digit_rt += 1;
-                }
-
-  (digit_lf, digit_rt)
-}
-
-fn parse_num(input_chars: &[char]) -> u32 {
-  input_chars.iter()
-    .collect::<String>()
-    .parse::<u32>()
-    .unwrap()
-}
-
-fn part_1(input: String) -> u32 {
-  let mut sum = 0;
-  let mut input_chars =
-    input.lines()
-      .map(|l| l.chars().collect::<Vec<char>>())
-      .collect::<Vec<Vec<char>>>();
-
-  for sym_i in 0..input_chars.len() {
-    for sym_j in 0..input_chars[sym_i].len() {
-
-      let symbol = input_chars[sym_i][sym_j];
-      if !symbol.is_digit(10) && symbol != '.' && symbol != '\0' { // actual symbol check
-        for sym_rng_i in max(sym_i-1,0)..=min(sym_i+1,input_chars.len()) {
-          for sym_rng_j in max(sym_j-1,0)..=min(sym_j+1,input_chars[sym_i].len()) {
-
-            let digit = input_chars[sym_rng_i][sym_rng_j];
-            if digit.is_digit(10) {                               // actual digit check
-
-              let (digit_lf, digit_rt) = number_bounds(sym_rng_j, sym_rng_i, &input_chars);
-
-              sum += parse_num(&input_chars[sym_rng_i][digit_lf..=digit_rt]);
-
-              input_chars[sym_rng_i][digit_lf..=digit_rt].fill('\0');
-
-            }
-
-          }
-        }
-      }
-
+use std::io::IsTerminal;
+
+use aoc_2023::{cli, days, Solution};
+use aoc_2023::days::day03::{self, Day03, GearRule, Style, Widths};
+
+fn run(program: &str, mut args: Vec<String>) -> Result<(), String> {
+    let usage = format!(
+        "Usage: {program} [--pad] [--gear-rule <RULE>] {}\n       \
+         {program} --render [--plain] [--pad] [--gear-rule <RULE>] [PATH|-]...\n\n\
+         Rows must all be the same width, unless --pad fills short rows with '.'.\n\
+         Part 2 sums the values of gears picked out by RULE, written as\n\
+         <symbols>:<count>:<product|sum|max>, e.g. \"#:3:sum\" or \"*:2-4:max\".\n\
+         The default, \"*:2:product\", is the gear ratio.\n\
+         --render colours part numbers green, numbers touching no symbol dim\n\
+         and gears yellow. --plain, or output that is not a terminal, marks\n\
+         part numbers as [467] and gears and their numbers as {{*}} and {{35}}.",
+        cli::OPTIONS
+    );
+
+    let rule = match cli::take_option(&mut args, "--gear-rule")? {
+        Some(rule) => rule.parse::<GearRule>().map_err(|e| format!("{e}\n{usage}"))?,
+        None => GearRule::ratio(),
+    };
+
+    let widths = match cli::take_flag(&mut args, "--pad") {
+        true  => Widths::Pad,
+        false => Widths::Strict,
+    };
+    let plain = cli::take_flag(&mut args, "--plain");
+    if cli::take_flag(&mut args, "--render") {
+        let style = match plain || !std::io::stdout().is_terminal() {
+            true  => Style::Plain,
+            false => Style::Ansi,
+        };
+        // Prints each input's schematic marked up with what was counted.
+        return cli::run_report(&usage, &args, |text| {
+            let schematic = day03::parse_schematic(text, widths).map_err(|e| e.to_string())?;
+            Ok(day03::render(&schematic, &rule, style))
+        });
     }
-  }
-  sum
-}
-
-fn part_2(input: String) -> u32 {
-  let mut sum = 0;
-  let input_chars =
-    input.lines()
-      .map(|l| l.chars().collect::<Vec<char>>())
-      .collect::<Vec<Vec<char>>>();
-
-
-  for sym_i in 0..input_chars.len() {
-    for sym_j in 0..input_chars[sym_i].len() {
-
-      let symbol_star = input_chars[sym_i][sym_j];
-      if symbol_star == '*' {                                       // actual symbol check
-        let mut nums_in_range: Vec<(usize,(usize,usize))> = vec![];
-
-        for sym_rng_i in max(sym_i-1,0)..=min(sym_i+1,input_chars.len()) {
-          for sym_rng_j in max(sym_j-1,0)..=min(sym_j+1,input_chars[sym_i].len()) {
-
-            let digit = input_chars[sym_rng_i][sym_rng_j];
-            if digit.is_digit(10) {                                 // actual digit check
-
-              let (digit_lf, digit_rt) = number_bounds(sym_rng_j, sym_rng_i, &input_chars);
-
-              nums_in_range.push((sym_rng_i,(digit_lf, digit_rt)));
-            }
-
-            
-          }
-        }
-
-        let mut unique_nums_in_range: Vec<(usize,(usize,usize))> = vec![];
-        for e in nums_in_range {
-          if !unique_nums_in_range.contains(&e) {
-            unique_nums_in_range.push(e);
-          }
-        }
-
-        if unique_nums_in_range.len() == 2 {
-          let (n1_row,(n1_lf,n1_rt)) = unique_nums_in_range[0];
-          let (n2_row,(n2_lf,n2_rt)) = unique_nums_in_range[1];
-          sum += parse_num(&input_chars[n1_row][n1_lf..=n1_rt]) *
-            parse_num(&input_chars[n2_row][n2_lf..=n2_rt]);
-        }
-        
-      }
-
+    if plain {
+        return Err(format!("--plain requires --render.\n{usage}"));
     }
-  }
-  sum
+    cli::run_with(3, &usage, &args, |text, parts| {
+        days::solve_with(
+            text,
+            parts,
+            |text| day03::parse_schematic(text, widths),
+            Day03::part1,
+            |schematic| Ok(rule.total(schematic))
+        )
+    })
 }
 
-
-
 fn main() {
-  let args = std::env::args().collect::<Vec<String>>();
-  if args.len() < 2 {panic!("Input file path must be passed as arg.");}
-
-  
-  let input = std::fs::read_to_string(args[1].clone()).unwrap();
-
-  let solution_1 = part_1(input.clone());
-  println!("{}", solution_1);
-
-  let solution_2 = part_2(input.clone());
-  println!("{}", solution_2);
-
+    let (program, args) = cli::args();
+    cli::exit_on_error(run(&program, args));
 }
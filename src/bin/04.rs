@@ -1,56 +1,50 @@
-fn count_winnings(line: &str) -> u32 {
-  let nums_str:Vec<&str> = line.split(":").skip(1)
-    .next().unwrap()
-    .split("|")
-    .collect();
-  let winn_nums: Vec<u32> = nums_str[0]
-    .trim().split_ascii_whitespace()
-    .map(|n| n.parse::<u32>().unwrap())
-    .collect::<Vec<u32>>();
-  let plyr_nums: Vec<u32> = nums_str[1]
-    .trim().split_ascii_whitespace()
-    .map(|n| n.parse::<u32>().unwrap())
-    .collect::<Vec<u32>>();
-  let count = plyr_nums.into_iter()
-  .filter(|pn| winn_nums.iter().any(|wn| wn == pn))
-  .count() as u32;
-  count
-}
+use aoc_2023::{cli, days, Solution};
+use aoc_2023::days::day04::{self, CopyRule, Day04, PastEnd};
+
+fn run(program: &str, mut args: Vec<String>) -> Result<(), String> {
+    let usage = format!(
+        "Usage: {program} [--copies <next|previous|weighted>] [--past-end <clamp|error>] {}\n       \
+         {program} --report [--copies <RULE>] [--past-end <clamp|error>] [PATH|-]...\n\n\
+         --copies picks which cards a card's matches win copies of: the next N\n\
+         (the default), the previous N, or N copies each of the next N.\n\
+         --past-end picks whether copies beyond the table are dropped (the\n\
+         default) or reported as an error.\n\
+         --report lists each card's matches, points, copies and the cards they\n\
+         were won from, then totals and a histogram of match counts.",
+        cli::OPTIONS
+    );
+
+    let rule = cli::take_option(&mut args, "--copies")?
+        .map(|r| r.parse::<CopyRule>())
+        .transpose()
+        .map_err(|e| format!("{e}\n{usage}"))?
+        .unwrap_or(CopyRule::Next);
+    let past_end = cli::take_option(&mut args, "--past-end")?
+        .map(|p| p.parse::<PastEnd>())
+        .transpose()
+        .map_err(|e| format!("{e}\n{usage}"))?
+        .unwrap_or(PastEnd::Clamp);
+
+    if cli::take_flag(&mut args, "--report") {
+        // Prints how copies of each input's cards were won, with totals.
+        return cli::run_report(&usage, &args, |text| {
+            let cards = Day04::parse(text).map_err(|e| e.to_string())?;
+            day04::report(&cards, rule, past_end).map_err(|e| e.to_string())
+        });
+    }
 
-fn part_1(input: String) -> u32 {
-  input.lines()
-    .map(|l| {
-      let wins = count_winnings(l);
-      if wins > 0 {return 2u32.pow(wins-1)} 
-      else        {return 0u32}
+    cli::run_with(4, &usage, &args, |text, parts| {
+        days::solve_with(
+            text,
+            parts,
+            Day04::parse,
+            Day04::part1,
+            |cards| day04::count_cards(cards, rule, past_end)
+        )
     })
-    .sum()
-}
-
-
-fn part_2(input: String) -> u32 {
-  let mut card_count: Vec<u32> = vec![1;input.lines().count()];
-
-  for (card_num, line) in input.lines().enumerate() {
-    let wins = count_winnings(line) as usize;
-    
-    for card in 0..wins {
-      card_count[card + card_num + 1] += card_count[card_num];
-    }
-  }
-  card_count.into_iter().sum()
 }
 
 fn main() {
-  let args = std::env::args().collect::<Vec<String>>();
-  if args.len() < 2 {panic!("Input file path must be passed as arg.");}
-
-  let input = std::fs::read_to_string(args[1].clone()).unwrap();
-
-  let solution_1: u32 = part_1(input.clone());
-
-  let solution_2: u32 = part_2(input.clone());
-
-  println!("{}", solution_1);
-  println!("{}", solution_2);  
-}
\ No newline at end of file
+    let (program, args) = cli::args();
+    cli::exit_on_error(run(&program, args));
+}
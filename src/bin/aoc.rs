@@ -0,0 +1,244 @@
+use aoc_2023::answers::Answers;
+use aoc_2023::cli::{self, Format, Record};
+use aoc_2023::days::{self, Answer, Day};
+
+const USAGE: &str = "\
+Usage: aoc run <DAY>... [--part <1|2>] [--input <PATH>]... [OPTIONS] [LEDGER]
+       aoc run --all [--part <1|2>] [OPTIONS] [LEDGER]
+
+Options:
+  --input <PATH>     Solve PATH instead of input/NN, or stdin for \"-\"; may be
+                     repeated to solve several inputs for one day
+  --time             Report parse and part durations, adding a \"parse\"
+                     record to json and csv output
+  --format <FORMAT>  Write answers as text, json (one object per line) or csv
+                     records with timings and ledger status [default: text]
+
+Ledger options, for input/NN only:
+  --check            Compare answers against the ledger, failing on mismatch
+  --record           Write answers into the ledger
+  --answers <PATH>   Ledger to use [default: answers.toml]";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Ledger {Ignore, Check, Record}
+
+struct RunArgs {
+    days: Vec<&'static Day>,
+    part: Option<u32>,
+    inputs: Vec<String>,
+    time: bool,
+    format: Format,
+    ledger: Ledger,
+    answers: String,
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut run = RunArgs {
+        days: vec![],
+        part: None,
+        inputs: vec![],
+        time: false,
+        format: Format::Text,
+        ledger: Ledger::Ignore,
+        answers: "answers.toml".to_string(),
+    };
+    let mut all = false;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--time" => run.time = true,
+            "--part" => {
+                let part = args.next().ok_or("--part requires a value.")?;
+                run.part = match part.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    p => return Err(format!("Invalid part: {p}")),
+                };
+            },
+            "--format" => {
+                let format = args.next().ok_or("--format requires a value.")?;
+                run.format = format.parse()?;
+            },
+            "--input" => {
+                let path = args.next().ok_or("--input requires a value.")?;
+                run.inputs.push(path.clone());
+            },
+            "--check" | "--record" if run.ledger != Ledger::Ignore => {
+                return Err("Only one of --check and --record can be used.".into());
+            },
+            "--check"  => run.ledger = Ledger::Check,
+            "--record" => run.ledger = Ledger::Record,
+            "--answers" => {
+                let path = args.next().ok_or("--answers requires a value.")?;
+                run.answers = path.clone();
+            },
+            day => {
+                let day = day.parse::<u32>().ok()
+                    .and_then(days::get)
+                    .ok_or(format!("Unknown day: {day}"))?;
+                run.days.push(day);
+            },
+        }
+    }
+
+    if all {
+        if !run.days.is_empty() {
+            return Err("--all cannot be combined with days.".to_string());
+        }
+        run.days = days::DAYS.iter().collect();
+    }
+    if run.days.is_empty() {
+        return Err("No days selected.".to_string());
+    }
+    if !run.inputs.is_empty() && run.days.len() > 1 {
+        return Err("--input can only be used with a single day.".to_string());
+    }
+    // The ledger holds one answer per day and part, for input/NN.
+    if !run.inputs.is_empty() && run.ledger != Ledger::Ignore {
+        return Err("--check and --record cannot be used with --input.".to_string());
+    }
+    Ok(run)
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let mut ledger = match args.ledger {
+        Ledger::Ignore => Answers::default(),
+        _ => Answers::load(&args.answers)?,
+    };
+    let mut mismatches = 0;
+    let mut failures = 0;
+
+    if args.format == Format::Csv {
+        println!("{}", cli::CSV_HEADER);
+    }
+    for day in args.days {
+        let inputs = match args.inputs.is_empty() {
+            true  => cli::read_input(&format!("./input/{:02}", day.number)).map(|i| vec![i]),
+            false => cli::read_inputs(&args.inputs),
+        };
+        // Days that fail are reported and skipped rather than ending the run,
+        // so the ledger is still saved with every answer marked recorded.
+        let inputs = match inputs {
+            Ok(inputs) => inputs,
+            Err(e) => {
+                eprintln!("{e}");
+                failures += 1;
+                continue;
+            },
+        };
+        let labelled = inputs.len() > 1;
+
+        let parts = match args.part {
+            Some(p) => vec![p],
+            None => vec![1, 2],
+        };
+        for input in inputs {
+            let solved = match day.solve(&input.text, &parts) {
+                Ok(solved) => solved,
+                Err(e) => {
+                    eprintln!("{}: {e}", input.label);
+                    failures += 1;
+                    continue;
+                },
+            };
+            let label = match labelled {
+                true  => format!(" ({})", input.label),
+                false => String::new(),
+            };
+            if args.time {
+                let record = Record {
+                    day: day.number,
+                    part: None,
+                    answer: "",
+                    elapsed: solved.parse_elapsed,
+                    input: &input.label,
+                    status: None,
+                };
+                match args.format {
+                    Format::Json => println!("{}", record.to_json()),
+                    Format::Csv  => println!("{}", record.to_csv()),
+                    Format::Text => println!(
+                        "Day {:02} parse{label}: [{:.2?}]",
+                        day.number, solved.parse_elapsed
+                    ),
+                }
+            }
+
+            for Answer {part, answer, elapsed} in solved.answers {
+                let recorded = ledger.get(day.number, part);
+                let status = match (args.ledger, recorded) {
+                    (Ledger::Ignore, _) => None,
+                    (Ledger::Check, Some(a)) if a == answer => Some("ok"),
+                    (Ledger::Check, Some(_)) => {
+                        mismatches += 1;
+                        Some("mismatch")
+                    },
+                    (Ledger::Check, None) => Some("unrecorded"),
+                    (Ledger::Record, _) => Some("recorded"),
+                };
+                let timing = match args.time {
+                    true  => format!(" [{elapsed:.2?}]"),
+                    false => String::new(),
+                };
+                let record = Record {
+                    day: day.number,
+                    part: Some(part),
+                    answer: &answer,
+                    elapsed,
+                    input: &input.label,
+                    status,
+                };
+                match args.format {
+                    Format::Json => println!("{}", record.to_json()),
+                    Format::Csv  => println!("{}", record.to_csv()),
+                    Format::Text => {
+                        let status = match (status, recorded) {
+                            (Some("mismatch"), Some(a)) => format!(" (MISMATCH, recorded {a})"),
+                            (Some(s), _) => format!(" ({s})"),
+                            (None, _) => String::new(),
+                        };
+                        println!(
+                            "Day {:02} part {}{}: {}{}{}",
+                            day.number, part, label, answer, status, timing
+                        )
+                    },
+                }
+
+                if args.ledger == Ledger::Record {
+                    ledger.record(day.number, part, answer);
+                }
+            }
+        }
+    }
+
+    if args.ledger == Ledger::Record {
+        ledger.save(&args.answers)?;
+    }
+    let mut errors = vec![];
+    if mismatches > 0 {
+        errors.push(format!("{mismatches} answer(s) differ from {}.", args.answers));
+    }
+    if failures > 0 {
+        errors.push(format!("{failures} input(s) could not be solved."));
+    }
+    match errors.is_empty() {
+        true  => Ok(()),
+        false => Err(errors.join("\n")),
+    }
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+
+    let result = match args.first().map(|a| a.as_str()) {
+        Some("run") => parse_run_args(&args[1..]).and_then(run),
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,299 @@
+use std::io::Read;
+use std::time::Duration;
+
+use crate::days::{self, Answer, Day, Error, Solved};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {Text, Json, Csv}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv"  => Ok(Format::Csv),
+            f => Err(format!("Unknown format: {f}")),
+        }
+    }
+}
+
+pub const CSV_HEADER: &str = "day,part,answer,elapsed_ns,input,status";
+
+// One answer as written by the json and csv formats. A `part` of `None` is
+// the parse phase, written as part "parse" with an empty answer, and
+// `status` is the result of checking the answer against a ledger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record<'a> {
+    pub day: u32,
+    pub part: Option<u32>,
+    pub answer: &'a str,
+    pub elapsed: Duration,
+    pub input: &'a str,
+    pub status: Option<&'a str>,
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"'  => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn csv_field(s: &str) -> String {
+    match s.contains([',', '"', '\n', '\r']) {
+        true  => format!("\"{}\"", s.replace('"', "\"\"")),
+        false => s.to_string(),
+    }
+}
+
+impl Record<'_> {
+    // A single line JSON object, so several records form JSON Lines.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ns\":{},\"input\":{},\"status\":{}}}",
+            self.day,
+            self.part.map_or("\"parse\"".to_string(), |p| p.to_string()),
+            json_string(self.answer),
+            self.elapsed.as_nanos(),
+            json_string(self.input),
+            self.status.map_or("null".to_string(), json_string)
+        )
+    }
+
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}",
+            self.day,
+            self.part.map_or("parse".to_string(), |p| p.to_string()),
+            csv_field(self.answer),
+            self.elapsed.as_nanos(),
+            csv_field(self.input),
+            csv_field(self.status.unwrap_or_default())
+        )
+    }
+}
+
+// Input text along with where it came from, for labelling answers and errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub label: String,
+    pub text: String,
+}
+
+// Reads the file at `path`, or stdin when `path` is "-".
+pub fn read_input(path: &str) -> Result<Input, String> {
+    if path == "-" {
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text)
+            .map_err(|e| format!("Failed to read stdin: {e}"))?;
+        return Ok(Input {label: "<stdin>".to_string(), text});
+    }
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {path}: {e}"))?;
+    Ok(Input {label: path.to_string(), text})
+}
+
+// Opens the file at `path`, or stdin when `path` is "-", without reading it.
+pub fn open_input(path: &str) -> Result<Box<dyn Read>, String> {
+    if path == "-" {
+        return Ok(Box::new(std::io::stdin().lock()));
+    }
+    let file = std::fs::File::open(path)
+        .map_err(|e| format!("Failed to open {path}: {e}"))?;
+    Ok(Box::new(file))
+}
+
+// Reads every path in order, with no paths meaning stdin.
+pub fn read_inputs(paths: &[String]) -> Result<Vec<Input>, String> {
+    if paths.iter().filter(|p| p.as_str() == "-").count() > 1 {
+        return Err("stdin can only be read once.".to_string());
+    }
+    match paths.is_empty() {
+        true  => Ok(vec![read_input("-")?]),
+        false => paths.iter().map(|p| read_input(p)).collect(),
+    }
+}
+
+pub const OPTIONS: &str = "[--format <text|json|csv>] [PATH|-]...";
+
+// Removes `name` and its value from `args`, for binaries with options of
+// their own.
+pub fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>, String> {
+    let Some(idx) = args.iter().position(|a| a == name) else {
+        return Ok(None);
+    };
+    if idx + 1 == args.len() {
+        return Err(format!("{name} requires a value."));
+    }
+    args.remove(idx);
+    Ok(Some(args.remove(idx)))
+}
+
+// Removes every `name` from `args`, returning whether there were any.
+pub fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    let len = args.len();
+    args.retain(|a| a != name);
+    args.len() != len
+}
+
+// The program name and its arguments.
+pub fn args() -> (String, Vec<String>) {
+    let mut args = std::env::args();
+    let program = args.next().unwrap_or_default();
+    (program, args.collect())
+}
+
+pub fn exit_on_error(result: Result<(), String>) {
+    if let Err(e) = result {
+        eprintln!("{e}");
+        std::process::exit(1);
+    }
+}
+
+// Solves both parts of `day` for each input named in `args`.
+pub fn run(day: &Day, program: &str, args: &[String]) -> Result<(), String> {
+    let usage = format!("Usage: {program} {OPTIONS}");
+    run_with(day.number, &usage, args, |text, parts| day.solve(text, parts))
+}
+
+// Like `run`, solving with `solve`. As text, a single input prints bare
+// answers and several print each answer labelled with its input.
+pub fn run_with(
+    day: u32,
+    usage: &str,
+    args: &[String],
+    solve: impl Fn(&str, &[u32]) -> Result<Solved, Error>
+) -> Result<(), String> {
+    let mut format = Format::Text;
+    let mut paths = vec![];
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                let f = args.next().ok_or("--format requires a value.")?;
+                format = f.parse()?;
+            },
+            "-" => paths.push(arg.clone()),
+            a if a.starts_with('-') => {
+                return Err(format!("Unknown option: {a}\n{usage}"));
+            },
+            _ => paths.push(arg.clone()),
+        }
+    }
+
+    let inputs = read_inputs(&paths)?;
+    let labelled = inputs.len() > 1;
+    let mut failures = 0;
+
+    if format == Format::Csv {
+        println!("{CSV_HEADER}");
+    }
+    for input in inputs {
+        let solved = match solve(&input.text, &[1, 2]) {
+            Ok(solved) => solved,
+            Err(e) => {
+                eprintln!("{}: {e}", input.label);
+                failures += 1;
+                continue;
+            },
+        };
+        for Answer {part, answer, elapsed} in solved.answers {
+            let record = Record {
+                day,
+                part: Some(part),
+                answer: &answer,
+                elapsed,
+                input: &input.label,
+                status: None,
+            };
+            match format {
+                Format::Json => println!("{}", record.to_json()),
+                Format::Csv  => println!("{}", record.to_csv()),
+                Format::Text if labelled => {
+                    println!("{} part {part}: {answer}", input.label)
+                },
+                Format::Text => println!("{answer}"),
+            }
+        }
+    }
+
+    match failures {
+        0 => Ok(()),
+        n => Err(format!("{n} input(s) could not be solved.")),
+    }
+}
+
+// Errors on any option left in `args` once a mode has taken its own, as
+// only paths should remain.
+pub fn reject_options(usage: &str, args: &[String]) -> Result<(), String> {
+    match args.iter().find(|a| a.starts_with('-') && *a != "-") {
+        Some(option) => Err(format!("Unexpected option for this mode: {option}\n{usage}")),
+        None => Ok(()),
+    }
+}
+
+// Runs a mode such as `--explain` that prints a report for each input named
+// in `args`, headed by the input's label when there are several.
+pub fn run_report(
+    usage: &str,
+    args: &[String],
+    mut report: impl FnMut(&str) -> Result<String, String>
+) -> Result<(), String> {
+    reject_options(usage, args)?;
+    let inputs = read_inputs(args)?;
+    let labelled = inputs.len() > 1;
+
+    for input in inputs {
+        let report = report(&input.text).map_err(|e| format!("{}: {e}", input.label))?;
+        if labelled {
+            println!("{}:", input.label);
+        }
+        print!("{report}");
+    }
+    Ok(())
+}
+
+// Entry point shared by the per-day binaries.
+pub fn main(day: u32) {
+    let (program, args) = args();
+    let day = days::get(day).unwrap_or_else(|| panic!("Day {day} is not registered."));
+    exit_on_error(run(day, &program, &args));
+}
+
+#[test]
+fn records() {
+    let record = Record {
+        day: 7,
+        part: Some(2),
+        answer: "a,\"b\"",
+        elapsed: Duration::from_micros(3),
+        input: "-",
+        status: None,
+    };
+    assert_eq!(
+        record.to_json(),
+        r#"{"day":7,"part":2,"answer":"a,\"b\"","elapsed_ns":3000,"input":"-","status":null}"#
+    );
+    assert_eq!(record.to_csv(), r#"7,2,"a,""b""",3000,-,"#);
+
+    let record = Record {part: None, answer: "", status: Some("ok"), ..record};
+    assert_eq!(
+        record.to_json(),
+        r#"{"day":7,"part":"parse","answer":"","elapsed_ns":3000,"input":"-","status":"ok"}"#
+    );
+    assert_eq!(record.to_csv(), "7,parse,,3000,-,ok");
+}
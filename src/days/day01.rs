@@ -0,0 +1,353 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::io::Read;
+use aho_corasick::{AhoCorasick, MatchKind};
+use rayon::prelude::*;
+use crate::{ParseError, Solution, SolveError};
+use crate::parse::Scanner;
+
+const ENGLISH: [&str; 9] =
+  ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+const GERMAN: [&str; 9] =
+  ["eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun"];
+const FRENCH: [&str; 9] =
+  ["un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf"];
+const ROMAN: [&str; 9] =
+  ["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX"];
+
+pub const BUILTINS: [&str; 4] = ["english", "german", "french", "roman"];
+
+// A matched digit or word, spanning bytes `start..end` of its line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+  pub start: usize,
+  pub end: usize,
+  pub value: u64,
+}
+
+// Words that stand for digits, matched alongside the digits 0 to 9 by an
+// automaton. The first digit is the leftmost match and the last digit is the
+// match ending latest, found by a second automaton reporting overlapping
+// matches. So "twone" gives 2 first and 1 last, and the longest word wins
+// where several match at the same place.
+#[derive(Debug, Clone)]
+pub struct Vocabulary {
+  leftmost: AhoCorasick,
+  overlapping: AhoCorasick,
+  values: Vec<u64>,
+}
+
+impl Vocabulary {
+  pub fn new(words: Vec<(String, u64)>) -> Vocabulary {
+    let words = (0..10)
+      .map(|d| (d.to_string(), d))
+      .chain(words)
+      .collect::<Vec<(String, u64)>>();
+    let patterns = words.iter().map(|(w, _)| w);
+
+    Vocabulary {
+      leftmost: AhoCorasick::builder()
+        .match_kind(MatchKind::LeftmostLongest)
+        .build(patterns.clone())
+        .expect("Vocabulary too large for automaton."),
+      overlapping: AhoCorasick::new(patterns)
+        .expect("Vocabulary too large for automaton."),
+      values: words.iter().map(|&(_, v)| v).collect(),
+    }
+  }
+
+  pub fn digits() -> Vocabulary {
+    Vocabulary::new(vec![])
+  }
+
+  pub fn builtin(name: &str) -> Option<Vocabulary> {
+    let words = match name {
+      "english" => ENGLISH,
+      "german"  => GERMAN,
+      "french"  => FRENCH,
+      "roman"   => ROMAN,
+      _ => return None,
+    };
+    Some(Vocabulary::new(words.iter()
+      .zip(1..)
+      .map(|(w, v)| (w.to_string(), v))
+      .collect()))
+  }
+
+  // One `word value` pair per line, with blank lines and `#` comments.
+  pub fn parse(input: &str) -> Result<Vocabulary, ParseError> {
+    let mut words = vec![];
+    let mut seen = HashSet::new();
+    for (idx, l) in input.lines().enumerate() {
+      let mut scanner = Scanner::new(idx + 1, l);
+      scanner.skip_whitespace();
+      if scanner.peek().is_none_or(|c| c == '#') {
+        continue;
+      }
+
+      let column = scanner.column();
+      let word = scanner.take_while(|c| !c.is_whitespace());
+      if !seen.insert(word) {
+        return Err(scanner.invalid(column, format!("duplicate word {word:?}")));
+      }
+      let column = scanner.column() + 1;
+      let value = scanner.number::<u64>()?;
+      if value > 9 {
+        return Err(scanner.invalid(column, format!("{value} is not a digit")));
+      }
+      scanner.finish()?;
+      words.push((word.to_string(), value));
+    }
+    Ok(Vocabulary::new(words))
+  }
+
+  // `source` is either a built-in name or the path of a vocabulary file.
+  pub fn load(source: &str) -> Result<Vocabulary, String> {
+    if let Some(vocabulary) = Vocabulary::builtin(source) {
+      return Ok(vocabulary);
+    }
+    let input = std::fs::read_to_string(source).map_err(|e| format!(
+      "{source} is not one of {} and could not be read: {e}",
+      BUILTINS.join(", ")
+    ))?;
+    Vocabulary::parse(&input).map_err(|e| format!("{source}: {e}"))
+  }
+
+  pub fn first(&self, line: &[u8]) -> Option<Token> {
+    self.leftmost.find(line).map(|m| Token {
+      start: m.start(),
+      end: m.end(),
+      value: self.values[m.pattern()],
+    })
+  }
+
+  pub fn last(&self, line: &[u8]) -> Option<Token> {
+    self.overlapping.find_overlapping_iter(line)
+      .max_by_key(|m| (m.end(), m.len()))
+      .map(|m| Token {
+        start: m.start(),
+        end: m.end(),
+        value: self.values[m.pattern()],
+      })
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Document {
+  text: String,
+  digits: Vocabulary,
+  words: Vocabulary,
+}
+
+// Lines without digits count as 0.
+fn calibration_value(line: &[u8], vocabulary: &Vocabulary) -> u64 {
+  match (vocabulary.first(line), vocabulary.last(line)) {
+    (Some(first), Some(last)) => 10*first.value + last.value,
+    _ => 0,
+  }
+}
+
+const OVERFLOW: &str = "calibration sum overflowed u64";
+
+fn calibration_sum(input: &str, vocabulary: &Vocabulary) -> Result<u64, SolveError> {
+  input.lines()
+    .try_fold(0u64, |sum, l| sum.checked_add(calibration_value(l.as_bytes(), vocabulary)))
+    .ok_or_else(|| SolveError::new(OVERFLOW))
+}
+
+fn add_sums(a: (u64, u64), b: (u64, u64)) -> Option<(u64, u64)> {
+  Some((a.0.checked_add(b.0)?, a.1.checked_add(b.1)?))
+}
+
+// Sums both parts over the newline separated lines of `chunk`, in place.
+fn chunk_sums(
+  chunk: &[u8],
+  digits: &Vocabulary,
+  words: &Vocabulary,
+  parallel: bool
+) -> Option<(u64, u64)> {
+  let values = |line: &[u8]| {
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    (calibration_value(line, digits), calibration_value(line, words))
+  };
+  match parallel {
+    true  => chunk.par_split(|&b| b == b'\n')
+      .map(|l| Some(values(l)))
+      .reduce(|| Some((0, 0)), |a, b| add_sums(a?, b?)),
+    false => chunk.split(|&b| b == b'\n')
+      .try_fold((0, 0), |sums, l| add_sums(sums, values(l))),
+  }
+}
+
+// Sums both parts straight from `reader`, reading about `chunk_size` bytes
+// at a time so memory stays bounded by the chunk size and the longest line.
+pub fn stream_sums(
+  mut reader: impl Read,
+  words: &Vocabulary,
+  chunk_size: usize,
+  parallel: bool
+) -> Result<(u64, u64), String> {
+  let digits = Vocabulary::digits();
+  let mut chunk = Vec::with_capacity(chunk_size);
+  let mut sums = (0, 0);
+
+  loop {
+    let read = reader.by_ref()
+      .take(chunk_size as u64)
+      .read_to_end(&mut chunk)
+      .map_err(|e| format!("Failed to read input: {e}"))?;
+    // Only whole lines are summed, the rest waits for the next chunk.
+    let end = match (read, chunk.iter().rposition(|&b| b == b'\n')) {
+      (0, _) => chunk.len(),
+      (_, Some(newline)) => newline + 1,
+      (_, None) => continue,
+    };
+
+    sums = chunk_sums(&chunk[..end], &digits, words, parallel)
+      .and_then(|chunk_sums| add_sums(sums, chunk_sums))
+      .ok_or(OVERFLOW)?;
+    chunk.drain(..end);
+    if read == 0 {
+      return Ok(sums);
+    }
+  }
+}
+
+// Part 2 reads digits spelled out with `words` instead of in English.
+pub fn parse_with(input: &str, words: Vocabulary) -> Result<Document, ParseError> {
+  Ok(Document {text: input.to_string(), digits: Vocabulary::digits(), words})
+}
+
+// Brackets the first and last tokens, or a single span where they overlap.
+fn highlight(line: &str, first: Token, last: Token) -> String {
+  let spans = match last.start < first.end {
+    true  => vec![(first.start, first.end.max(last.end))],
+    false => vec![(first.start, first.end), (last.start, last.end)],
+  };
+  let mut result = String::with_capacity(line.len() + 4);
+  let mut prev = 0;
+  for (start, end) in spans {
+    result.push_str(&line[prev..start]);
+    result.push('[');
+    result.push_str(&line[start..end]);
+    result.push(']');
+    prev = end;
+  }
+  result.push_str(&line[prev..]);
+  result
+}
+
+// Describes how each line is read in both parts, returning the report and
+// the number of line readings that found no digit.
+pub fn explain(document: &Document) -> (String, usize) {
+  let mut report = String::new();
+  let mut errors = 0;
+  let vocabularies = [(1, &document.digits), (2, &document.words)];
+
+  for (idx, line) in document.text.lines().enumerate() {
+    for (part, vocabulary) in vocabularies {
+      write!(report, "line {}, part {part}: ", idx + 1).unwrap();
+      match (vocabulary.first(line.as_bytes()), vocabulary.last(line.as_bytes())) {
+        (Some(first), Some(last)) => writeln!(
+          report,
+          "{}  first {:?} at bytes {}..{}, last {:?} at bytes {}..{}, value {}",
+          highlight(line, first, last),
+          &line[first.start..first.end], first.start, first.end,
+          &line[last.start..last.end], last.start, last.end,
+          10*first.value + last.value
+        ).unwrap(),
+        _ => {
+          errors += 1;
+          writeln!(report, "{line}  error: no digit found").unwrap();
+        },
+      }
+    }
+  }
+  for (part, vocabulary) in vocabularies {
+    match calibration_sum(&document.text, vocabulary) {
+      Ok(sum) => writeln!(report, "part {part} sum: {sum}"),
+      Err(e)  => writeln!(report, "part {part} sum: error: {e}"),
+    }.unwrap();
+  }
+  (report, errors)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+  type Input = Document;
+  type Output1 = u64;
+  type Output2 = u64;
+
+  fn parse(input: &str) -> Result<Self::Input, ParseError> {
+    parse_with(input, Vocabulary::builtin("english").unwrap())
+  }
+
+  fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+    calibration_sum(&input.text, &input.digits)
+  }
+
+  fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+    calibration_sum(&input.text, &input.words)
+  }
+}
+
+#[test]
+fn example() {
+  let input = Day01::parse(include_str!("../../input/examples/01")).unwrap();
+  assert_eq!(Day01::part1(&input), Ok(142));
+
+  let input = Day01::parse(include_str!("../../input/examples/01-2")).unwrap();
+  assert_eq!(Day01::part2(&input), Ok(281));
+}
+
+#[test]
+fn overlapping_words() {
+  let english = Vocabulary::builtin("english").unwrap();
+  assert_eq!(calibration_value(b"twone", &english), 21);
+  assert_eq!(calibration_value(b"eightwo", &english), 82);
+  assert_eq!(calibration_value(b"xoneightx", &english), 18);
+  assert_eq!(calibration_value(b"7", &english), 77);
+  assert_eq!(calibration_value(b"twone", &Vocabulary::digits()), 0);
+}
+
+#[test]
+fn vocabularies() {
+  let german = Vocabulary::builtin("german").unwrap();
+  assert_eq!(calibration_value("xfünfzweiacht".as_bytes(), &german), 58);
+
+  let roman = Vocabulary::builtin("roman").unwrap();
+  assert_eq!(calibration_value(b"xVIIIyIX", &roman), 89);
+  assert_eq!(calibration_value(b"IV", &roman), 44);
+
+  let custom = Vocabulary::parse("# Spanish\nuno 1\n\ndos 2\n").unwrap();
+  assert_eq!(calibration_value(b"unodos3dos", &custom), 12);
+  assert!(Vocabulary::parse("uno 1\nuno 2").is_err());
+  assert!(Vocabulary::parse("diez 10").is_err());
+}
+
+#[test]
+fn explanation() {
+  let input = Day01::parse("twone\nabc\n").unwrap();
+  let (report, errors) = explain(&input);
+  assert_eq!(errors, 3);
+  assert!(report.contains(
+    "line 1, part 2: [twone]  first \"two\" at bytes 0..3, last \"one\" at bytes 2..5, value 21"
+  ));
+  assert!(report.contains("line 2, part 1: abc  error: no digit found"));
+  assert_eq!(highlight("a1b2c", Token {start: 1, end: 2, value: 1}, Token {start: 3, end: 4, value: 2}), "a[1]b[2]c");
+}
+
+#[test]
+fn streaming() {
+  let input = include_str!("../../input/examples/01-2");
+  let english = Vocabulary::builtin("english").unwrap();
+  for chunk_size in [1, 7, 4096] {
+    for parallel in [false, true] {
+      let sums = stream_sums(input.as_bytes(), &english, chunk_size, parallel);
+      assert_eq!(sums, Ok((209, 281)));
+    }
+  }
+  assert_eq!(stream_sums(&b"1\r\n2"[..], &english, 2, false), Ok((33, 33)));
+  assert_eq!(add_sums((u64::MAX, 0), (1, 0)), None);
+}
@@ -0,0 +1,604 @@
+use std::collections::BTreeMap;
+use crate::{ParseError, Solution, SolveError};
+use crate::parse::Scanner;
+
+// Cube counts by colour, where colours not in the map count as 0.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Set {
+  cubes: BTreeMap<String, u32>,
+}
+
+impl<S: Into<String>> FromIterator<(S, u32)> for Set {
+  fn from_iter<I: IntoIterator<Item = (S, u32)>>(iter: I) -> Self {
+    let mut set = Set::default();
+    for (colour, count) in iter {
+      *set.cubes.entry(colour.into()).or_default() += count;
+    }
+    set
+  }
+}
+
+impl Set {
+  pub fn get(&self, colour: &str) -> u32 {
+    self.cubes.get(colour).copied().unwrap_or(0)
+  }
+
+  pub fn colours(&self) -> impl Iterator<Item = (&str, u32)> {
+    self.cubes.iter().map(|(c, &n)| (c.as_str(), n))
+  }
+
+  pub fn maximise(mut self, rhs: &Self) -> Self {
+    for (colour, &count) in &rhs.cubes {
+      let entry = self.cubes.entry(colour.clone()).or_default();
+      *entry = (*entry).max(count);
+    }
+    self
+  }
+
+  pub fn is_possible(&self, maximum: &Self) -> bool {
+    self.colours().all(|(colour, count)| count <= maximum.get(colour))
+  }
+
+  // The product of the counts of the colours in the set, so a colour given
+  // as 0 gives 0 but a missing colour is left out. `None` on overflow.
+  pub fn power(&self) -> Option<u64> {
+    self.cubes.values().try_fold(1u64, |power, &n| power.checked_mul(n.into()))
+  }
+}
+
+// Written in the same form as a draw, e.g. `4 blue, 12 red`.
+impl std::fmt::Display for Set {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let cubes = self.colours()
+      .map(|(colour, count)| format!("{count} {colour}"))
+      .collect::<Vec<String>>();
+    write!(f, "{}", cubes.join(", "))
+  }
+}
+
+impl std::ops::Add for Set {
+  type Output = Self;
+  fn add(mut self, rhs: Self) -> Self::Output {
+    for (colour, count) in rhs.cubes {
+      *self.cubes.entry(colour).or_default() += count;
+    }
+    self
+  }
+}
+
+impl std::iter::Sum for Set {
+  fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+    iter.fold(Self::default(), |acc, x| acc + x)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+  pub id: u32,
+  pub draws: Vec<Set>,
+}
+
+// A colour in one draw, numbered from 1, that exceeds what the bag holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+  pub draw: usize,
+  pub colour: String,
+  pub count: u32,
+  pub limit: u32,
+}
+
+impl std::fmt::Display for Violation {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f, "draw {} has {} {}, bag holds {}",
+      self.draw, self.count, self.colour, self.limit
+    )
+  }
+}
+
+impl Game {
+  // The fewest cubes of each colour that could have produced every draw.
+  pub fn minimal_bag(&self) -> Set {
+    self.draws.iter().fold(Set::default(), |bag, draw| bag.maximise(draw))
+  }
+
+  pub fn is_possible(&self, bag: &Set) -> bool {
+    self.draws.iter().all(|draw| draw.is_possible(bag))
+  }
+
+  pub fn violations(&self, bag: &Set) -> Vec<Violation> {
+    self.draws.iter()
+      .enumerate()
+      .flat_map(|(idx, draw)| draw.colours()
+        .filter(|&(colour, count)| count > bag.get(colour))
+        .map(move |(colour, count)| Violation {
+          draw: idx + 1,
+          colour: colour.to_string(),
+          count,
+          limit: bag.get(colour),
+        }))
+      .collect()
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {Number(&'a str), Word(&'a str), Colon, Comma, Semicolon}
+
+impl Token<'_> {
+  fn text(&self) -> &str {
+    match self {
+      Token::Number(t) | Token::Word(t) => t,
+      Token::Colon     => ":",
+      Token::Comma     => ",",
+      Token::Semicolon => ";",
+    }
+  }
+}
+
+// Splits a game record into tokens along with their columns. Words are whole
+// runs of letters, so "reddish" is never read as "red".
+fn tokenize(line: usize, text: &str) -> Result<Vec<(usize, Token<'_>)>, ParseError> {
+  let mut scanner = Scanner::new(line, text);
+  let mut tokens = vec![];
+  loop {
+    scanner.skip_whitespace();
+    let column = scanner.column();
+    let token = match scanner.peek() {
+      None      => return Ok(tokens),
+      Some(':') => Token::Colon,
+      Some(',') => Token::Comma,
+      Some(';') => Token::Semicolon,
+      Some(c) if c.is_ascii_digit() => {
+        tokens.push((column, Token::Number(scanner.take_while(|c| c.is_ascii_digit()))));
+        continue;
+      },
+      Some(c) if c.is_alphabetic() => {
+        tokens.push((column, Token::Word(scanner.take_while(|c| c.is_alphabetic()))));
+        continue;
+      },
+      Some(_) => {
+        return Err(scanner.unexpected(&["<number>", "<colour>", ":", ",", ";"]));
+      },
+    };
+    scanner.next_char();
+    tokens.push((column, token));
+  }
+}
+
+// Reads `Game <id>: <count> <colour>, ...; ...` from the tokens of a line.
+struct Parser<'a> {
+  line: usize,
+  end: usize,
+  tokens: std::vec::IntoIter<(usize, Token<'a>)>,
+}
+
+impl<'a> Parser<'a> {
+  fn next<T>(
+    &mut self,
+    expected: &[&'static str],
+    f: impl FnOnce(Token<'a>) -> Option<T>
+  ) -> Result<(usize, T), ParseError> {
+    match self.tokens.next() {
+      Some((column, token)) => match f(token) {
+        Some(value) => Ok((column, value)),
+        None => Err(ParseError::UnexpectedToken {
+          line: self.line,
+          column,
+          found: token.text().to_string(),
+          expected: expected.to_vec(),
+        }),
+      },
+      None => Err(ParseError::UnexpectedChar {
+        line: self.line,
+        column: self.end,
+        found: None,
+        expected: expected.to_vec(),
+      }),
+    }
+  }
+
+  fn number(&mut self) -> Result<u32, ParseError> {
+    let (column, text) = self.next(&["<number>"], |t| match t {
+      Token::Number(n) => Some(n),
+      _ => None,
+    })?;
+    text.parse().map_err(|_| ParseError::Invalid {
+      line: self.line,
+      column,
+      message: format!("invalid number {text:?}"),
+    })
+  }
+
+  // `<count> <colour>, ...`, stopping before anything but a comma.
+  fn draw(&mut self) -> Result<Set, ParseError> {
+    let mut draw = Set::default();
+    loop {
+      let count = self.number()?;
+      let (column, colour) = self.next(&["<colour>"], |t| match t {
+        Token::Word(w) => Some(w),
+        _ => None,
+      })?;
+      if draw.colours().any(|(c, _)| c == colour) {
+        return Err(ParseError::Invalid {
+          line: self.line,
+          column,
+          message: format!("colour {colour:?} appears twice in one draw"),
+        });
+      }
+      draw = draw + Set::from_iter([(colour, count)]);
+
+      match self.tokens.as_slice().first() {
+        Some((_, Token::Comma)) => {self.tokens.next();},
+        _ => return Ok(draw),
+      }
+    }
+  }
+
+  fn game(mut self) -> Result<Game, ParseError> {
+    self.next(&["Game"], |t| (t == Token::Word("Game")).then_some(()))?;
+    let id = self.number()?;
+    self.next(&[":"], |t| (t == Token::Colon).then_some(()))?;
+
+    let mut draws = vec![self.draw()?];
+    while !self.tokens.as_slice().is_empty() {
+      self.next(&[",", ";"], |t| (t == Token::Semicolon).then_some(()))?;
+      draws.push(self.draw()?);
+    }
+    Ok(Game {id, draws})
+  }
+}
+
+fn parser(line: usize, text: &str) -> Result<Parser<'_>, ParseError> {
+  let tokens = tokenize(line, text)?;
+  Ok(Parser {line, end: text.chars().count() + 1, tokens: tokens.into_iter()})
+}
+
+fn parse_game(line: usize, text: &str) -> Result<Game, ParseError> {
+  parser(line, text)?.game()
+}
+
+// A bag written like a draw, e.g. `12 red, 13 green, 14 blue`, which may be
+// split over several lines. Blank lines and `#` comments are skipped.
+pub fn parse_bag(input: &str) -> Result<Set, ParseError> {
+  let mut bag = Set::default();
+  for (idx, l) in input.lines().enumerate() {
+    if l.trim().is_empty() || l.trim_start().starts_with('#') {
+      continue;
+    }
+    let mut parser = parser(idx + 1, l)?;
+    let draw = parser.draw()?;
+    if let Some((column, token)) = parser.tokens.next() {
+      return Err(ParseError::UnexpectedToken {
+        line: idx + 1,
+        column,
+        found: token.text().to_string(),
+        expected: vec![","],
+      });
+    }
+    if let Some((colour, _)) = draw.colours().find(|&(c, _)| bag.colours().any(|(b, _)| b == c)) {
+      return Err(ParseError::Invalid {
+        line: idx + 1,
+        column: 1,
+        message: format!("colour {colour:?} given more than once"),
+      });
+    }
+    bag = bag + draw;
+  }
+  Ok(bag)
+}
+
+pub fn default_bag() -> Set {
+  Set::from_iter([("red", 12), ("green", 13), ("blue", 14)])
+}
+
+// Lists whether each game is possible with `bag`, why not if it is not, and
+// the smallest bag that makes every game possible.
+pub fn query(games: &[Game], bag: &Set) -> String {
+  let mut report = String::new();
+  for game in games {
+    let violations = game.violations(bag);
+    let verdict = match violations.is_empty() {
+      true  => "possible".to_string(),
+      false => format!("impossible: {}", violations.iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<String>>()
+        .join("; ")),
+    };
+    report += &format!("Game {}: {verdict}\n", game.id);
+  }
+
+  let minimal = games.iter()
+    .fold(Set::default(), |bag, game| bag.maximise(&game.minimal_bag()));
+  let possible = games.iter().filter(|g| g.is_possible(bag)).count();
+  report += &format!("{possible} of {} games possible with {bag}\n", games.len());
+  report += &format!("Minimal bag for every game: {minimal}\n");
+  report
+}
+
+// ln(n!) for every n below `len`.
+fn ln_factorials(len: usize) -> Vec<f64> {
+  let mut table = Vec::with_capacity(len);
+  let mut sum = 0.0;
+  for n in 0..len {
+    if n > 1 {
+      sum += (n as f64).ln();
+    }
+    table.push(sum);
+  }
+  table
+}
+
+fn ln_choose(ln_fact: &[f64], n: u32, k: u32) -> f64 {
+  match k <= n {
+    true  => ln_fact[n as usize] - ln_fact[k as usize] - ln_fact[(n - k) as usize],
+    false => f64::NEG_INFINITY,
+  }
+}
+
+// The log-probability of every draw in `game` when each is taken without
+// replacement from a bag holding `counts` of `colours`, with the cubes put
+// back between draws.
+fn ln_likelihood(game: &Game, colours: &[String], counts: &[u32], ln_fact: &[f64]) -> f64 {
+  let total = counts.iter().sum::<u32>();
+  game.draws.iter()
+    .map(|draw| {
+      let drawn = draw.colours().map(|(_, n)| n).sum::<u32>();
+      colours.iter()
+        .zip(counts)
+        .map(|(colour, &count)| ln_choose(ln_fact, count, draw.get(colour)))
+        .sum::<f64>() - ln_choose(ln_fact, total, drawn)
+    })
+    .sum()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+  pub bag: Set,
+  pub ln_likelihood: f64,
+  pub lower_bound: Set,
+  pub lower_ln_likelihood: f64,
+  // Whether the search stopped at the cube limit, where a larger bag may
+  // be more likely still.
+  pub capped: bool,
+}
+
+// Estimates the most likely bag behind `game`'s draws by hill climbing from
+// the `maximise` lower bound, adding or removing one cube at a time while
+// that raises the likelihood. Only colours seen in the game are estimated,
+// as unseen colours would only make the draws less likely. Bags hold at most
+// `max_cubes` cubes, so there is no estimate if the lower bound holds more.
+pub fn estimate_bag(game: &Game, max_cubes: u32) -> Option<Estimate> {
+  let lower_bound = game.minimal_bag();
+  let colours = lower_bound.colours().map(|(c, _)| c.to_string()).collect::<Vec<String>>();
+  let lower = lower_bound.colours().map(|(_, n)| n).collect::<Vec<u32>>();
+  lower.iter()
+    .try_fold(0u32, |total, &n| total.checked_add(n))
+    .filter(|&total| total <= max_cubes)?;
+  let ln_fact = ln_factorials(max_cubes as usize + 2);
+  let score = |counts: &[u32]| ln_likelihood(game, &colours, counts, &ln_fact);
+
+  // Bags one cube away from `counts`, keeping to the lower bound, paired
+  // with whether they would exceed the cube limit.
+  let moves = |counts: &[u32]| {
+    let total = counts.iter().sum::<u32>();
+    let mut moves = vec![];
+    for idx in 0..counts.len() {
+      if counts[idx] > lower[idx] {
+        let mut next = counts.to_vec();
+        next[idx] -= 1;
+        moves.push((next, false));
+      }
+      let mut next = counts.to_vec();
+      next[idx] += 1;
+      moves.push((next, total == max_cubes));
+    }
+    moves
+  };
+
+  let lower_ln_likelihood = score(&lower);
+  let mut counts = lower.clone();
+  let mut best = lower_ln_likelihood;
+  loop {
+    let step = moves(&counts).into_iter()
+      .filter(|(_, over)| !over)
+      .map(|(next, _)| (score(&next), next))
+      .filter(|(ln_likelihood, _)| *ln_likelihood > best + 1e-12)
+      .max_by(|a, b| a.0.total_cmp(&b.0));
+    match step {
+      Some((ln_likelihood, next)) => (best, counts) = (ln_likelihood, next),
+      None => break,
+    }
+  }
+  let capped = moves(&counts).into_iter()
+    .any(|(next, over)| over && score(&next) > best + 1e-12);
+
+  Some(Estimate {
+    bag: colours.into_iter().zip(counts).collect(),
+    ln_likelihood: best,
+    lower_bound,
+    lower_ln_likelihood,
+    capped,
+  })
+}
+
+// Compares each game's estimated bag with its lower bound, giving the
+// natural log of how likely each makes the game's draws.
+pub fn estimate_report(games: &[Game], max_cubes: u32) -> String {
+  let mut report = String::new();
+  for game in games {
+    let Some(estimate) = estimate_bag(game, max_cubes) else {
+      report += &format!(
+        "Game {}: not estimable, lower bound {} holds more than {max_cubes} cubes\n",
+        game.id,
+        game.minimal_bag()
+      );
+      continue;
+    };
+    let extra = estimate.bag.colours().map(|(_, n)| n).sum::<u32>()
+      - estimate.lower_bound.colours().map(|(_, n)| n).sum::<u32>();
+    report += &format!(
+      "Game {}: estimate {}, log-likelihood {:.4}; lower bound {}, log-likelihood {:.4}; {extra} extra cube(s){}\n",
+      game.id,
+      estimate.bag,
+      estimate.ln_likelihood,
+      estimate.lower_bound,
+      estimate.lower_ln_likelihood,
+      if estimate.capped {", stopped at the cube limit"} else {""},
+    );
+  }
+  report
+}
+
+pub fn sum_possible_ids(games: &[Game], bag: &Set) -> u32 {
+  games.iter()
+    .filter(|game| game.is_possible(bag))
+    .map(|game| game.id)
+    .sum()
+}
+
+fn part_1(games: &[Game]) -> u32 {
+  sum_possible_ids(games, &default_bag())
+}
+
+// Powers cover red, green and blue as well as any colours drawn, so a game
+// that never draws one of red, green or blue has power 0.
+fn part_2(games: &[Game]) -> Result<u64, SolveError> {
+  let none = default_bag().colours().map(|(c, _)| (c, 0)).collect::<Set>();
+  games.iter().try_fold(0u64, |sum, game| {
+    let power = game.minimal_bag().maximise(&none).power()
+      .ok_or_else(|| SolveError::new(format!("power of game {} overflows", game.id)))?;
+    sum.checked_add(power)
+      .ok_or_else(|| SolveError::new(format!("sum of powers overflows at game {}", game.id)))
+  })
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+  type Input = Vec<Game>;
+  type Output1 = u32;
+  type Output2 = u64;
+
+  fn parse(input: &str) -> Result<Self::Input, ParseError> {
+    input.lines()
+      .enumerate()
+      .map(|(idx, l)| parse_game(idx + 1, l))
+      .collect()
+  }
+
+  fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+    Ok(part_1(input))
+  }
+
+  fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+    part_2(input)
+  }
+}
+
+#[test]
+fn example() {
+  let input = Day02::parse(include_str!("../../input/examples/02")).unwrap();
+  assert_eq!(Day02::part1(&input), Ok(8));
+  assert_eq!(Day02::part2(&input), Ok(2286));
+}
+
+#[test]
+fn extra_colours() {
+  let input = Day02::parse(
+    "Game 1: 3 yellow, 2 red; 1 purple\nGame 2: 2 red, 4 blue; 1 green\n"
+  ).unwrap();
+  assert_eq!(input[0].draws[0].get("yellow"), 3);
+  assert_eq!(Day02::part1(&input), Ok(2));
+  // Game 1 draws no green or blue, so only game 2 has any power.
+  assert_eq!(Day02::part2(&input), Ok(8));
+  assert_eq!(input[0].minimal_bag().power(), Some(6));
+
+  let bag = Set::from_iter([("yellow", 3), ("red", 2), ("purple", 1)]);
+  assert_eq!(sum_possible_ids(&input, &bag), 1);
+
+  let input = Day02::parse("Game 1: 3 red, 4 green; 2 red\n").unwrap();
+  assert_eq!(Day02::part2(&input), Ok(0));
+
+  let input = Day02::parse("Game 1: 2000 red, 2000 green, 2000 blue\n").unwrap();
+  assert_eq!(Day02::part2(&input), Ok(8_000_000_000));
+  let input = Day02::parse(
+    "Game 1: 1 red, 1 green, 1 blue\nGame 2: 70000 red, 70000 green, 70000 blue, 70000 pink\n"
+  ).unwrap();
+  assert_eq!(Day02::part2(&input), Err(SolveError::new("power of game 2 overflows")));
+}
+
+#[test]
+fn grammar() {
+  let game = parse_game(1, "Game 7: 1 red, 2 blue; 3 green").unwrap();
+  assert_eq!(game.id, 7);
+  assert_eq!(game.draws, [
+    Set::from_iter([("red", 1), ("blue", 2)]),
+    Set::from_iter([("green", 3)]),
+  ]);
+
+  let game = parse_game(1, "Game 1: 3 reddish").unwrap();
+  assert_eq!((game.draws[0].get("red"), game.draws[0].get("reddish")), (0, 3));
+
+  let error = |text| parse_game(1, text).unwrap_err().to_string();
+  assert_eq!(error("Game 1: 3 red 4 blue"), "line 1, column 15: unexpected \"4\", expected one of ',', ';'");
+  assert_eq!(error("Game 1: 3 red,"), "line 1, column 15: unexpected end of line, expected <number>");
+  assert_eq!(error("Game 1: 3 red, 1 red"), "line 1, column 18: colour \"red\" appears twice in one draw");
+  assert_eq!(error("Gam 1: 3 red"), "line 1, column 1: unexpected \"Gam\", expected 'Game'");
+}
+
+#[test]
+fn bags_and_queries() {
+  let bag = parse_bag("# limits\n12 red, 13 green\n14 blue\n").unwrap();
+  assert_eq!(bag, default_bag());
+  assert_eq!(parse_bag(&bag.to_string()), Ok(bag.clone()));
+  assert!(parse_bag("1 red\n2 red").is_err());
+  assert!(parse_bag("1 red; 2 blue").is_err());
+
+  let games = Day02::parse(include_str!("../../input/examples/02")).unwrap();
+  let report = query(&games, &bag);
+  assert!(report.contains("Game 1: possible\n"));
+  assert!(report.contains("Game 3: impossible: draw 1 has 20 red, bag holds 12\n"));
+  assert!(report.contains("3 of 5 games possible with 14 blue, 13 green, 12 red\n"));
+  assert!(report.contains("Minimal bag for every game: 15 blue, 13 green, 20 red\n"));
+}
+
+#[test]
+fn estimates() {
+  // Single cubes drawn as red three times out of four fit 3 red to 1 blue.
+  let game = parse_game(1, "Game 1: 1 red; 1 red; 1 red; 1 blue").unwrap();
+  let estimate = estimate_bag(&game, 100).unwrap();
+  assert_eq!(estimate.bag, Set::from_iter([("red", 3), ("blue", 1)]));
+  assert_eq!(estimate.lower_bound, Set::from_iter([("red", 1), ("blue", 1)]));
+  assert!((estimate.ln_likelihood - (27.0f64 / 256.0).ln()).abs() < 1e-9);
+  assert!((estimate.lower_ln_likelihood - (1.0f64 / 16.0).ln()).abs() < 1e-9);
+  assert!(!estimate.capped);
+
+  // Drawing the whole lower bound at once is certain, so it stays the estimate.
+  let game = parse_game(1, "Game 2: 2 red, 1 blue").unwrap();
+  let estimate = estimate_bag(&game, 100).unwrap();
+  assert_eq!(estimate.bag, estimate.lower_bound);
+  assert!(estimate.ln_likelihood.abs() < 1e-9);
+
+  let estimate = estimate_bag(&parse_game(1, "Game 3: 1 red; 1 red; 1 red; 1 blue").unwrap(), 3).unwrap();
+  assert_eq!(estimate.bag, Set::from_iter([("red", 2), ("blue", 1)]));
+  assert!(estimate.capped);
+
+  let games = Day02::parse(include_str!("../../input/examples/02")).unwrap();
+  let report = estimate_report(&games, 100);
+  assert_eq!(report.lines().count(), games.len());
+  for game in &games {
+    let estimate = estimate_bag(game, 100).unwrap();
+    assert!(estimate.lower_bound.is_possible(&estimate.bag));
+    assert!(estimate.ln_likelihood >= estimate.lower_ln_likelihood);
+  }
+
+  // Lower bounds beyond the cube limit, or beyond a u32, are not estimated.
+  let games = Day02::parse("Game 1: 3000000000 red\nGame 2: 3000000000 red, 3000000000 blue\n").unwrap();
+  assert_eq!(estimate_bag(&games[0], 100), None);
+  assert_eq!(estimate_bag(&games[1], u32::MAX), None);
+  assert_eq!(
+    estimate_report(&games, 100),
+    "Game 1: not estimable, lower bound 3000000000 red holds more than 100 cubes\n\
+     Game 2: not estimable, lower bound 3000000000 blue, 3000000000 red holds more than 100 cubes\n"
+  );
+}
@@ -0,0 +1,468 @@
+use crate::{ParseError, Solution, SolveError};
+use crate::geometry::Pos;
+use crate::grid::Grid;
+use crate::parse::parse_row;
+
+// A run of digits on one row, covering columns `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+  pub row: usize,
+  pub start: usize,
+  pub end: usize,
+  pub value: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+  pub pos: Pos,
+  pub symbol: char,
+}
+
+fn is_symbol(c: char) -> bool {
+  !c.is_ascii_digit() && c != '.'
+}
+
+// The numbers and symbols of an engine schematic, along with which numbers
+// each symbol touches, including diagonally. Numbers and symbols are
+// referred to by their index, in reading order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schematic {
+  grid: Grid<char>,
+  numbers: Vec<Number>,
+  symbols: Vec<Symbol>,
+  number_symbols: Vec<Vec<usize>>,
+  symbol_numbers: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+  pub fn new(grid: Grid<char>) -> Result<Self, ParseError> {
+    let mut numbers = vec![];
+    let mut symbols = vec![];
+    // The index of the number covering each cell.
+    let mut number_at = Grid::filled(grid.width(), grid.height(), None);
+
+    for (row, cells) in grid.rows().enumerate() {
+      let mut col = 0;
+      while col < cells.len() {
+        if !cells[col].is_ascii_digit() {
+          if is_symbol(cells[col]) {
+            symbols.push(Symbol {pos: Pos::new(row, col), symbol: cells[col]});
+          }
+          col += 1;
+          continue;
+        }
+        let start = col;
+        while col < cells.len() && cells[col].is_ascii_digit() {
+          number_at[(row, col)] = Some(numbers.len());
+          col += 1;
+        }
+        let text = cells[start..col].iter().collect::<String>();
+        let value = text.parse().map_err(|_| ParseError::Invalid {
+          line: row + 1,
+          column: start + 1,
+          message: format!("number {text} is too large"),
+        })?;
+        numbers.push(Number {row, start, end: col, value});
+      }
+    }
+
+    let mut number_symbols = vec![vec![]; numbers.len()];
+    let mut symbol_numbers = vec![];
+    for (idx, symbol) in symbols.iter().enumerate() {
+      let mut touching = symbol.pos.adjacent()
+        .filter_map(|pos| number_at.get(pos).copied().flatten())
+        .collect::<Vec<usize>>();
+      touching.sort_unstable();
+      touching.dedup();
+      for &number in &touching {
+        number_symbols[number].push(idx);
+      }
+      symbol_numbers.push(touching);
+    }
+
+    Ok(Schematic {grid, numbers, symbols, number_symbols, symbol_numbers})
+  }
+
+  pub fn grid(&self) -> &Grid<char> {
+    &self.grid
+  }
+
+  pub fn numbers(&self) -> &[Number] {
+    &self.numbers
+  }
+
+  pub fn symbols(&self) -> &[Symbol] {
+    &self.symbols
+  }
+
+  // Indices of the symbols touching the number at `number`.
+  pub fn symbols_of(&self, number: usize) -> &[usize] {
+    &self.number_symbols[number]
+  }
+
+  // Indices of the numbers touching the symbol at `symbol`.
+  pub fn numbers_of(&self, symbol: usize) -> &[usize] {
+    &self.symbol_numbers[symbol]
+  }
+
+  // Numbers touching any symbol.
+  pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+    self.numbers.iter()
+      .zip(&self.number_symbols)
+      .filter(|(_, symbols)| !symbols.is_empty())
+      .map(|(number, _)| number)
+  }
+
+  // Numbers touching at least one `symbol`, each given once.
+  pub fn numbers_touching(&self, symbol: char) -> impl Iterator<Item = &Number> {
+    self.numbers.iter()
+      .zip(&self.number_symbols)
+      .filter(move |(_, symbols)| symbols.iter().any(|&s| self.symbols[s].symbol == symbol))
+      .map(|(number, _)| number)
+  }
+
+  // Symbols touching exactly `count` numbers, along with those numbers.
+  pub fn symbols_touching(&self, count: usize) -> impl Iterator<Item = (&Symbol, Vec<&Number>)> {
+    self.symbols.iter()
+      .zip(&self.symbol_numbers)
+      .filter(move |(_, numbers)| numbers.len() == count)
+      .map(|(symbol, numbers)| {
+        (symbol, numbers.iter().map(|&n| &self.numbers[n]).collect())
+      })
+  }
+}
+
+// How rows of differing widths are handled: rejected, or padded with `.` to
+// the widest row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Widths {Strict, Pad}
+
+// Trailing ASCII whitespace is dropped from each row, as are blank lines at
+// the end. Other ASCII whitespace and control chars are rejected, while any
+// other char that is not an ASCII digit or `.` is a symbol, non-ASCII ones
+// included.
+pub fn parse_schematic(input: &str, widths: Widths) -> Result<Schematic, ParseError> {
+  let lines = input.lines()
+    .map(|l| l.trim_end_matches(|c: char| c.is_ascii_whitespace()))
+    .collect::<Vec<&str>>();
+  let len = lines.iter().rposition(|l| !l.is_empty()).map_or(0, |idx| idx + 1);
+
+  let mut rows = lines[..len].iter()
+    .enumerate()
+    .map(|(idx, l)| parse_row(idx + 1, l, &["<digit>", ".", "<symbol>"], |c| {
+      (!c.is_ascii_whitespace() && !c.is_ascii_control()).then_some(c)
+    }))
+    .collect::<Result<Vec<Vec<char>>, ParseError>>()?;
+
+  match widths {
+    Widths::Strict => {
+      let width = rows.first().map_or(0, |r| r.len());
+      if let Some(idx) = rows.iter().position(|r| r.len() != width) {
+        return Err(ParseError::Invalid {
+          line: idx + 1,
+          column: rows[idx].len().min(width) + 1,
+          message: format!("row has width {}, expected {width}", rows[idx].len()),
+        });
+      }
+    },
+    Widths::Pad => {
+      let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+      for row in &mut rows {
+        row.resize(width, '.');
+      }
+    },
+  }
+  let grid = Grid::from_rows(rows).expect("Rows have the same width.");
+  Schematic::new(grid)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combine {Product, Sum, Max}
+
+impl Combine {
+  pub fn apply(self, values: impl Iterator<Item = u32>) -> u64 {
+    let values = values.map(u64::from);
+    match self {
+      Combine::Product => values.product(),
+      Combine::Sum     => values.sum(),
+      Combine::Max     => values.max().unwrap_or(0),
+    }
+  }
+}
+
+impl std::str::FromStr for Combine {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "product" => Ok(Combine::Product),
+      "sum"     => Ok(Combine::Sum),
+      "max"     => Ok(Combine::Max),
+      c => Err(format!("Unknown combining operation: {c}")),
+    }
+  }
+}
+
+// Which symbols count as gears, how many numbers they must touch, and how
+// those numbers combine into the gear's value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GearRule {
+  pub symbols: Vec<char>,
+  pub arity: std::ops::RangeInclusive<usize>,
+  pub combine: Combine,
+}
+
+impl GearRule {
+  // A `*` touching exactly two numbers, worth their product.
+  pub fn ratio() -> Self {
+    GearRule {symbols: vec!['*'], arity: 2..=2, combine: Combine::Product}
+  }
+
+  pub fn is_gear(&self, schematic: &Schematic, symbol: usize) -> bool {
+    self.symbols.contains(&schematic.symbols()[symbol].symbol)
+      && self.arity.contains(&schematic.numbers_of(symbol).len())
+  }
+
+  // Each gear along with the numbers it touches and its value.
+  pub fn gears<'a>(
+    &'a self,
+    schematic: &'a Schematic
+  ) -> impl Iterator<Item = (&'a Symbol, Vec<&'a Number>, u64)> {
+    schematic.symbols().iter()
+      .enumerate()
+      .filter(|&(idx, _)| self.is_gear(schematic, idx))
+      .map(|(idx, symbol)| {
+        let numbers = schematic.numbers_of(idx).iter()
+          .map(|&n| &schematic.numbers()[n])
+          .collect::<Vec<&Number>>();
+        let value = self.combine.apply(numbers.iter().map(|n| n.value));
+        (symbol, numbers, value)
+      })
+  }
+
+  pub fn total(&self, schematic: &Schematic) -> u64 {
+    self.gears(schematic).map(|(_, _, value)| value).sum()
+  }
+}
+
+// `<symbols>:<count>:<operation>`, where the count is a number or an
+// inclusive range such as `2-4`, e.g. `#:3:sum` or `*:2:product`.
+impl std::str::FromStr for GearRule {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let [symbols, arity, combine] = s.split(':').collect::<Vec<&str>>()[..] else {
+      return Err(format!("Gear rule {s:?} is not of the form <symbols>:<count>:<operation>"));
+    };
+    if symbols.is_empty() {
+      return Err(format!("Gear rule {s:?} has no symbols"));
+    }
+    let count = |n: &str| n.parse::<usize>()
+      .map_err(|e| format!("Invalid count {n:?} in gear rule: {e}"));
+    let arity = match arity.split_once('-') {
+      Some((min, max)) => count(min)?..=count(max)?,
+      None => count(arity)?..=count(arity)?,
+    };
+    if arity.is_empty() {
+      return Err(format!("Gear rule {s:?} has an empty count range"));
+    }
+    Ok(GearRule {symbols: symbols.chars().collect(), arity, combine: combine.parse()?})
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {Ansi, Plain}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mark {None, Part, Unattached, Gear}
+
+impl Mark {
+  fn wrap(self, text: &str, style: Style) -> String {
+    match (style, self) {
+      (_, Mark::None)                  => text.to_string(),
+      (Style::Ansi, Mark::Part)        => format!("\x1b[32m{text}\x1b[0m"),
+      (Style::Ansi, Mark::Unattached)  => format!("\x1b[2m{text}\x1b[0m"),
+      (Style::Ansi, Mark::Gear)        => format!("\x1b[1;33m{text}\x1b[0m"),
+      (Style::Plain, Mark::Part)       => format!("[{text}]"),
+      (Style::Plain, Mark::Unattached) => text.to_string(),
+      (Style::Plain, Mark::Gear)       => format!("{{{text}}}"),
+    }
+  }
+}
+
+// Reprints the schematic showing what each part counted. With ANSI colours,
+// part numbers are green, numbers touching no symbol are dim, and gears under
+// `rule` are highlighted along with their numbers. Plain text marks part
+// numbers as `[467]` and gears and their numbers as `{*}` and `{35}`.
+pub fn render(schematic: &Schematic, rule: &GearRule, style: Style) -> String {
+  let mut number_marks = schematic.numbers().iter()
+    .enumerate()
+    .map(|(idx, _)| match schematic.symbols_of(idx).is_empty() {
+      true  => Mark::Unattached,
+      false => Mark::Part,
+    })
+    .collect::<Vec<Mark>>();
+  let mut symbol_marks = vec![Mark::None; schematic.symbols().len()];
+  for (idx, mark) in symbol_marks.iter_mut().enumerate() {
+    if rule.is_gear(schematic, idx) {
+      *mark = Mark::Gear;
+      for &number in schematic.numbers_of(idx) {
+        number_marks[number] = Mark::Gear;
+      }
+    }
+  }
+
+  // Numbers and symbols are both in reading order, so each is reached as the
+  // grid is walked.
+  let mut numbers = schematic.numbers().iter().zip(number_marks).peekable();
+  let mut symbols = schematic.symbols().iter().zip(symbol_marks).peekable();
+  let grid = schematic.grid();
+  let mut rendered = String::new();
+  for (row, cells) in grid.rows().enumerate() {
+    let mut col = 0;
+    while col < cells.len() {
+      if let Some((number, mark)) = numbers.next_if(|(n, _)| (n.row, n.start) == (row, col)) {
+        let text = cells[number.start..number.end].iter().collect::<String>();
+        rendered += &mark.wrap(&text, style);
+        col = number.end;
+        continue;
+      }
+      match symbols.next_if(|(s, _)| s.pos == Pos::new(row, col)) {
+        Some((symbol, mark)) => rendered += &mark.wrap(&symbol.symbol.to_string(), style),
+        None => rendered.push(cells[col]),
+      }
+      col += 1;
+    }
+    rendered.push('\n');
+  }
+  rendered
+}
+
+fn part_1(schematic: &Schematic) -> u32 {
+  schematic.part_numbers()
+    .map(|number| number.value)
+    .sum()
+}
+
+fn part_2(schematic: &Schematic) -> u64 {
+  GearRule::ratio().total(schematic)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+  type Input = Schematic;
+  type Output1 = u32;
+  type Output2 = u64;
+
+  fn parse(input: &str) -> Result<Self::Input, ParseError> {
+    parse_schematic(input, Widths::Strict)
+  }
+
+  fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+    Ok(part_1(input))
+  }
+
+  fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+    Ok(part_2(input))
+  }
+}
+
+#[test]
+fn example() {
+  let input = Day03::parse(include_str!("../../input/examples/03")).unwrap();
+  assert_eq!(Day03::part1(&input), Ok(4361));
+  assert_eq!(Day03::part2(&input), Ok(467835));
+}
+
+#[test]
+fn symbols_on_edges() {
+  let input = Day03::parse("*12\n3..\n..#\n").unwrap();
+  assert_eq!(Day03::part1(&input), Ok(15));
+  assert_eq!(Day03::part2(&input), Ok(36));
+}
+
+#[test]
+fn adjacency() {
+  let schematic = Day03::parse("12.#\n..*.\n3..4\n").unwrap();
+  assert_eq!(schematic.numbers(), [
+    Number {row: 0, start: 0, end: 2, value: 12},
+    Number {row: 2, start: 0, end: 1, value: 3},
+    Number {row: 2, start: 3, end: 4, value: 4},
+  ]);
+  assert_eq!(schematic.symbols_of(0), [1]);
+  assert_eq!(schematic.symbols_of(1), []);
+  assert_eq!(schematic.numbers_of(0), []);
+  assert_eq!(schematic.numbers_of(1), [0, 2]);
+
+  let values = |numbers: Vec<&Number>| numbers.iter().map(|n| n.value).collect::<Vec<u32>>();
+  assert_eq!(values(schematic.part_numbers().collect()), [12, 4]);
+  assert_eq!(values(schematic.numbers_touching('*').collect()), [12, 4]);
+  assert_eq!(values(schematic.numbers_touching('#').collect()), []);
+  assert_eq!(schematic.symbols_touching(0).map(|(s, _)| s.symbol).collect::<String>(), "#");
+  let (gear, numbers) = schematic.symbols_touching(2).next().unwrap();
+  assert_eq!((gear.pos, values(numbers)), (Pos::new(1, 2), vec![12, 4]));
+
+  assert!(Day03::parse("99999999999\n").is_err());
+}
+
+#[test]
+fn gear_rules() {
+  let schematic = Day03::parse(include_str!("../../input/examples/03")).unwrap();
+  assert_eq!("*:2:product".parse(), Ok(GearRule::ratio()));
+  assert_eq!(GearRule::ratio().total(&schematic), 467835);
+
+  let rule = "*#:1-2:max".parse::<GearRule>().unwrap();
+  let gears = rule.gears(&schematic).map(|(s, _, v)| (s.symbol, v)).collect::<Vec<(char, u64)>>();
+  assert_eq!(gears, [('*', 467), ('#', 633), ('*', 617), ('*', 755)]);
+  assert_eq!("$+:1:sum".parse::<GearRule>().unwrap().total(&schematic), 664 + 592);
+
+  assert!("*:2".parse::<GearRule>().is_err());
+  assert!("*:3-2:sum".parse::<GearRule>().is_err());
+  assert!("*:2:mean".parse::<GearRule>().is_err());
+}
+
+#[test]
+fn rendering() {
+  let schematic = Day03::parse(include_str!("../../input/examples/03")).unwrap();
+  assert_eq!(render(&schematic, &GearRule::ratio(), Style::Plain), "\
+{467}..114..
+...{*}......
+..{35}..[633].
+......#...
+[617]*......
+.....+.58.
+..[592].....
+......{755}.
+...$.{*}....
+.[664].{598}..
+");
+
+  let rendered = render(&schematic, &GearRule::ratio(), Style::Ansi);
+  assert!(rendered.starts_with("\x1b[1;33m467\x1b[0m..\x1b[2m114\x1b[0m..\n"));
+  let stripped = rendered.replace("\x1b[0m", "")
+    .replace("\x1b[32m", "")
+    .replace("\x1b[2m", "")
+    .replace("\x1b[1;33m", "");
+  assert_eq!(stripped, include_str!("../../input/examples/03"));
+}
+
+#[test]
+fn ragged_rows() {
+  let input = "12.\n..*  \n3\n\n";
+  let error = parse_schematic(input, Widths::Strict).unwrap_err();
+  assert_eq!(error.to_string(), "line 3, column 2: row has width 1, expected 3");
+
+  let schematic = parse_schematic(input, Widths::Pad).unwrap();
+  assert_eq!(schematic.grid().width(), 3);
+  assert_eq!(schematic.grid().height(), 3);
+  assert_eq!(part_1(&schematic), 12);
+
+  let schematic = parse_schematic("7é.\n.٣.\n", Widths::Strict).unwrap();
+  let symbols = schematic.symbols().iter().map(|s| s.symbol).collect::<String>();
+  assert_eq!(symbols, "é٣");
+  assert_eq!(part_1(&schematic), 7);
+
+  let error = parse_schematic("1.\n.\t.\n", Widths::Pad).unwrap_err();
+  assert_eq!(error.to_string(), "line 2, column 2: unexpected '\\t', expected one of <digit>, '.', <symbol>");
+}
@@ -0,0 +1,369 @@
+use std::collections::HashSet;
+use crate::{ParseError, Solution, SolveError};
+use crate::parse::Scanner;
+
+// A card's winning numbers, as a bitset when they all lie in 0..=99.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Winning {
+  Bits(u128),
+  Set(HashSet<u32>),
+}
+
+impl Winning {
+  pub fn new(numbers: &[u32]) -> Self {
+    match numbers.iter().all(|&n| n < 100) {
+      true  => Winning::Bits(numbers.iter().fold(0, |bits, &n| bits | 1 << n)),
+      false => Winning::Set(numbers.iter().copied().collect()),
+    }
+  }
+
+  pub fn contains(&self, number: u32) -> bool {
+    match self {
+      Winning::Bits(bits) => number < 100 && bits & 1 << number != 0,
+      Winning::Set(set)   => set.contains(&number),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+  pub id: u32,
+  pub winning: Winning,
+  pub have: Vec<u32>,
+}
+
+impl Card {
+  // How many of the numbers the player has are winning numbers.
+  pub fn matches(&self) -> u32 {
+    self.have.iter()
+      .filter(|&&n| self.winning.contains(n))
+      .count() as u32
+  }
+
+  // `None` when there are too many matches for the points to fit a u128.
+  pub fn points(&self) -> Option<u128> {
+    match self.matches() {
+      0 => Some(0),
+      m => 1u128.checked_shl(m - 1),
+    }
+  }
+}
+
+// Parses `Card <id>: <winning numbers> | <numbers the player has>`, where
+// the id must be `expected_id`.
+fn parse_card(line: usize, text: &str, expected_id: u32) -> Result<Card, ParseError> {
+  let mut scanner = Scanner::new(line, text);
+  scanner.literal("Card")?;
+  scanner.skip_whitespace();
+  let column = scanner.column();
+  let id = scanner.number::<u32>()?;
+  if id != expected_id {
+    return Err(scanner.invalid(
+      column,
+      format!("card {id} is out of sequence, expected card {expected_id}")
+    ));
+  }
+  scanner.literal(":")?;
+  let winning = scanner.numbers()?;
+  scanner.literal("|")?;
+  let have = scanner.numbers()?;
+  scanner.finish()?;
+  Ok(Card {id, winning: Winning::new(&winning), have})
+}
+
+// Each card's points, erroring at the first card whose points, or the total
+// so far, overflow.
+fn card_points(cards: &[Card]) -> Result<Vec<u128>, SolveError> {
+  let mut points = Vec::with_capacity(cards.len());
+  let mut total = 0u128;
+  for card in cards {
+    let p = card.points().ok_or_else(|| SolveError::new(format!(
+      "card {} has {} matches, too many points to count",
+      card.id,
+      card.matches()
+    )))?;
+    total = total.checked_add(p).ok_or_else(|| {
+      SolveError::new(format!("total points overflow at card {}", card.id))
+    })?;
+    points.push(p);
+  }
+  Ok(points)
+}
+
+fn part_1(cards: &[Card]) -> Result<u128, SolveError> {
+  Ok(card_points(cards)?.into_iter().sum())
+}
+
+
+// Which cards a card's matches win copies of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyRule {
+  // One copy each of the next `matches` cards, as in the puzzle.
+  Next,
+  // One copy each of the `matches` cards before it, scoring from the last.
+  Previous,
+  // `matches` copies each of the next `matches` cards.
+  Weighted,
+}
+
+impl std::str::FromStr for CopyRule {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "next"     => Ok(CopyRule::Next),
+      "previous" => Ok(CopyRule::Previous),
+      "weighted" => Ok(CopyRule::Weighted),
+      r => Err(format!("Unknown copy rule: {r}")),
+    }
+  }
+}
+
+// What happens to copies of cards beyond either end of the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PastEnd {Clamp, Error}
+
+impl std::str::FromStr for PastEnd {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "clamp" => Ok(PastEnd::Clamp),
+      "error" => Ok(PastEnd::Error),
+      p => Err(format!("Unknown past-end handling: {p}")),
+    }
+  }
+}
+
+// How many of each card are held once every card has won its copies.
+pub fn cascade(cards: &[Card], rule: CopyRule, past_end: PastEnd) -> Result<Vec<u128>, SolveError> {
+  trace_cascade(cards, rule, past_end, |_, _, _| {})
+}
+
+// Like `cascade`, calling `on_copy(source, target, won)` with card indices
+// each time a card wins copies of another.
+fn trace_cascade(
+  cards: &[Card],
+  rule: CopyRule,
+  past_end: PastEnd,
+  mut on_copy: impl FnMut(usize, usize, u128)
+) -> Result<Vec<u128>, SolveError> {
+  let mut copies = vec![1u128; cards.len()];
+  let order = match rule {
+    CopyRule::Previous => (0..cards.len()).rev().collect::<Vec<usize>>(),
+    _ => (0..cards.len()).collect(),
+  };
+
+  for idx in order {
+    let matches = cards[idx].matches() as usize;
+    let (targets, direction) = match rule {
+      CopyRule::Previous => (idx.saturating_sub(matches)..idx, "precede"),
+      _ => (idx + 1..(idx + 1 + matches).min(cards.len()), "follow"),
+    };
+    if targets.len() < matches && past_end == PastEnd::Error {
+      return Err(SolveError::new(format!(
+        "card {} wins copies of {matches} cards, but only {} {direction} it",
+        cards[idx].id,
+        targets.len()
+      )));
+    }
+
+    let overflow = || SolveError::new(format!("copies of card {} overflow", cards[idx].id));
+    let won = match rule {
+      CopyRule::Weighted => copies[idx].checked_mul(matches as u128).ok_or_else(overflow)?,
+      _ => copies[idx],
+    };
+    for target in targets {
+      copies[target] = copies[target].checked_add(won).ok_or_else(overflow)?;
+      on_copy(idx, target, won);
+    }
+  }
+  Ok(copies)
+}
+
+// The sum of `copies`, as returned by `cascade`.
+fn total_cards(cards: &[Card], copies: &[u128]) -> Result<u128, SolveError> {
+  copies.iter()
+    .zip(cards)
+    .try_fold(0u128, |sum, (&copies, card)| sum.checked_add(copies).ok_or_else(|| {
+      SolveError::new(format!("total number of cards overflows at card {}", card.id))
+    }))
+}
+
+pub fn count_cards(cards: &[Card], rule: CopyRule, past_end: PastEnd) -> Result<u128, SolveError> {
+  total_cards(cards, &cascade(cards, rule, past_end)?)
+}
+
+// Each card's matches, points and copies, with the cards its copies were
+// won from, followed by totals and a histogram of match counts.
+pub fn report(cards: &[Card], rule: CopyRule, past_end: PastEnd) -> Result<String, SolveError> {
+  let mut sources = vec![vec![]; cards.len()];
+  let copies = trace_cascade(cards, rule, past_end, |source, target, won| {
+    sources[target].push((source, won));
+  })?;
+
+  let points = card_points(cards)?;
+  let mut report = String::new();
+  let rows = cards.iter().zip(&copies).zip(&sources).zip(&points);
+  for (((card, &copies), sources), points) in rows {
+    report += &format!(
+      "Card {}: matches {}, points {points}, copies {copies}",
+      card.id,
+      card.matches()
+    );
+    if !sources.is_empty() {
+      let from = sources.iter()
+        .map(|&(source, won)| format!("card {} ({won})", cards[source].id))
+        .collect::<Vec<String>>();
+      report += &format!(", won {} from {}", copies - 1, from.join(", "));
+    }
+    report.push('\n');
+  }
+
+  report += &format!("Total points: {}\n", points.iter().sum::<u128>());
+  report += &format!("Total cards: {}\n", total_cards(cards, &copies)?);
+  // The first card with the most copies.
+  if let Some((idx, most)) = copies.iter().enumerate().rev().max_by_key(|&(_, c)| c) {
+    report += &format!("Most copies: card {} with {most}\n", cards[idx].id);
+  }
+
+  let max_matches = cards.iter().map(|card| card.matches()).max().unwrap_or(0);
+  let mut histogram = vec![0usize; max_matches as usize + 1];
+  for card in cards {
+    histogram[card.matches() as usize] += 1;
+  }
+  let tallest = histogram.iter().copied().max().unwrap_or(0);
+  report += "Matches:\n";
+  for (matches, &count) in histogram.iter().enumerate() {
+    // Bars are at most 40 wide, and any count above 0 shows.
+    let bar = (count * 40).div_ceil(tallest.max(1));
+    report += &format!("{matches:>3} | {} {count}\n", "#".repeat(bar));
+  }
+  Ok(report)
+}
+
+fn part_2(cards: &[Card]) -> Result<u128, SolveError> {
+  count_cards(cards, CopyRule::Next, PastEnd::Clamp)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+  type Input = Vec<Card>;
+  type Output1 = u128;
+  type Output2 = u128;
+
+  // Card ids must run 1, 2, 3, ... in order, as part 2 relies on them.
+  fn parse(input: &str) -> Result<Self::Input, ParseError> {
+    input.lines()
+      .enumerate()
+      .map(|(idx, l)| parse_card(idx + 1, l, idx as u32 + 1))
+      .collect()
+  }
+
+  fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+    part_1(input)
+  }
+
+  fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+    part_2(input)
+  }
+}
+
+#[test]
+fn example() {
+  let input = Day04::parse(include_str!("../../input/examples/04")).unwrap();
+  assert_eq!(Day04::part1(&input), Ok(13));
+  assert_eq!(Day04::part2(&input), Ok(30));
+}
+
+#[test]
+fn cards() {
+  let card = parse_card(1, "Card 1: 0 99 5 | 99 0 100 4 5 5", 1).unwrap();
+  assert!(matches!(card.winning, Winning::Bits(_)));
+  assert_eq!((card.matches(), card.points()), (4, Some(8)));
+
+  let card = parse_card(1, "Card 1: 7 150 | 150 151 7 8", 1).unwrap();
+  assert!(matches!(card.winning, Winning::Set(_)));
+  assert_eq!(card.matches(), 2);
+
+  let error = Day04::parse("Card 1: 1 | 1\nCard 3: 2 | 2\n").unwrap_err();
+  assert_eq!(error.to_string(), "line 2, column 6: card 3 is out of sequence, expected card 2");
+}
+
+#[test]
+fn copy_rules() {
+  let cards = Day04::parse(include_str!("../../input/examples/04")).unwrap();
+  assert_eq!(cascade(&cards, CopyRule::Next, PastEnd::Error), Ok(vec![1, 2, 4, 8, 14, 1]));
+  assert_eq!(count_cards(&cards, CopyRule::Weighted, PastEnd::Error), Ok(1 + 5 + 15 + 45 + 80 + 1));
+  assert_eq!(count_cards(&cards, CopyRule::Previous, PastEnd::Clamp), Ok(6 + 3 + 2 + 1 + 1 + 1));
+  assert_eq!(
+    count_cards(&cards, CopyRule::Previous, PastEnd::Error).unwrap_err().to_string(),
+    "card 2 wins copies of 2 cards, but only 1 precede it"
+  );
+
+  let cards = Day04::parse("Card 1: 1 2 | 1 2\nCard 2: 5 | 5\n").unwrap();
+  assert_eq!(count_cards(&cards, CopyRule::Next, PastEnd::Clamp), Ok(1 + 2));
+  assert_eq!(
+    count_cards(&cards, CopyRule::Next, PastEnd::Error).unwrap_err().to_string(),
+    "card 1 wins copies of 2 cards, but only 1 follow it"
+  );
+
+  // Each card doubles the copies of every later card.
+  let input = (1..=130).map(|id| format!("Card {id}: 1 | 1\n")).collect::<String>();
+  let cards = Day04::parse(&input).unwrap();
+  let cards = cards.into_iter()
+    .map(|card| Card {have: vec![1; 130 - card.id as usize], ..card})
+    .collect::<Vec<Card>>();
+  assert_eq!(count_cards(&cards[2..], CopyRule::Next, PastEnd::Error), Ok(u128::MAX));
+  assert!(count_cards(&cards, CopyRule::Next, PastEnd::Error).is_err());
+}
+
+#[test]
+fn reports() {
+  let cards = Day04::parse(include_str!("../../input/examples/04")).unwrap();
+  let report = report(&cards, CopyRule::Next, PastEnd::Error).unwrap();
+  assert_eq!(report, "\
+Card 1: matches 4, points 8, copies 1
+Card 2: matches 2, points 2, copies 2, won 1 from card 1 (1)
+Card 3: matches 2, points 2, copies 4, won 3 from card 1 (1), card 2 (2)
+Card 4: matches 1, points 1, copies 8, won 7 from card 1 (1), card 2 (2), card 3 (4)
+Card 5: matches 0, points 0, copies 14, won 13 from card 1 (1), card 3 (4), card 4 (8)
+Card 6: matches 0, points 0, copies 1
+Total points: 13
+Total cards: 30
+Most copies: card 5 with 14
+Matches:
+  0 | ######################################## 2
+  1 | #################### 1
+  2 | ######################################## 2
+  3 |  0
+  4 | #################### 1
+");
+}
+
+#[test]
+fn many_matches() {
+  // Repeated numbers each count as a match.
+  let ones = |n| vec!["1"; n].join(" ");
+  let input = format!("Card 1: 1 | {}\nCard 2: 1 | {}\n", ones(128), ones(1));
+  let cards = Day04::parse(&input).unwrap();
+  assert_eq!(cards[0].points(), Some(1 << 127));
+  assert_eq!(Day04::part1(&cards), Ok((1 << 127) + 1));
+
+  let input = format!("Card 1: 1 | {}\nCard 2: 1 | {}\n", ones(128), ones(129));
+  let cards = Day04::parse(&input).unwrap();
+  assert_eq!(cards[1].points(), None);
+  assert_eq!(
+    Day04::part1(&cards).unwrap_err().to_string(),
+    "card 2 has 129 matches, too many points to count"
+  );
+  assert!(report(&cards, CopyRule::Next, PastEnd::Clamp).is_err());
+
+  let input = format!("Card 1: 1 | {}\nCard 2: 1 | {}\n", ones(128), ones(128));
+  let cards = Day04::parse(&input).unwrap();
+  assert_eq!(
+    Day04::part1(&cards).unwrap_err().to_string(),
+    "total points overflow at card 2"
+  );
+}
@@ -0,0 +1,140 @@
+use rayon::iter::{ParallelIterator, IntoParallelIterator};
+use crate::{ParseError, Solution, SolveError};
+use crate::parse::Scanner;
+
+
+#[derive(Debug, Clone, Copy)]
+struct Map {
+  src_start: u64,
+  dst_start: u64,
+  range: u64,
+}
+
+impl Map {
+  fn new(dst_start: u64, src_start: u64, range: u64) -> Self {
+    Map{src_start, dst_start, range}
+  }
+
+  fn can_convert(&self, input: u64) -> bool {
+    input >= self.src_start && input < self.src_start + self.range
+  }
+
+  fn convert(&self, input: u64) -> u64 {
+      input - self.src_start + self.dst_start
+  }
+}
+
+
+
+#[derive(Debug, Clone)]
+pub struct Almanac {
+  seeds: Vec<u64>,
+  maps: Vec<Vec<Map>>,
+}
+
+fn parse_input(input: &str) -> Result<Almanac, ParseError> {
+  let mut lines = input.lines()
+    .enumerate()
+    .map(|(idx, l)| Scanner::new(idx + 1, l));
+
+  let mut seeds_line = lines.next().unwrap_or(Scanner::new(1, ""));
+  seeds_line.literal("seeds:")?;
+  seeds_line.skip_whitespace();
+  let column = seeds_line.column();
+  let seeds = seeds_line.numbers::<u64>()?;
+  seeds_line.finish()?;
+  // Part 2 reads the seeds as (start, length) pairs.
+  if seeds.is_empty() || seeds.len() % 2 == 1 {
+    return Err(seeds_line.invalid(column, format!(
+      "expected seeds in (start, length) pairs, found {} number(s)",
+      seeds.len()
+    )));
+  }
+
+  let mut maps = Vec::<Vec<Map>>::new();
+  for mut line in lines {
+    line.skip_whitespace();
+    if line.is_empty() {
+      continue;
+    }
+
+    if !line.peek().is_some_and(|c| c.is_ascii_digit()) {     // map header
+      line.take_while(|c| c.is_alphabetic() || c == '-');
+      line.literal(" map:")?;
+      line.finish()?;
+      maps.push(vec![]);
+      continue;
+    }
+
+    let map = Map::new(line.number()?, line.number()?, line.number()?);
+    line.finish()?;
+    match maps.last_mut() {
+      Some(ms) => ms.push(map),
+      None     => return Err(line.invalid(1, "map values before any map header".to_string())),
+    }
+  }
+
+  Ok(Almanac {seeds, maps})
+}
+
+fn locate(seed: u64, maps: &[Vec<Map>]) -> u64 {
+  let mut s = seed;
+  for ms in maps {
+    for m in ms {
+      if m.can_convert(s) {
+        s = m.convert(s);
+        break;
+      }
+    }
+  }
+  s
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+  type Input = Almanac;
+  type Output1 = u64;
+  type Output2 = u64;
+
+  fn parse(input: &str) -> Result<Self::Input, ParseError> {
+    parse_input(input)
+  }
+
+  fn part1(almanac: &Self::Input) -> Result<Self::Output1, SolveError> {
+    Ok(almanac.seeds.iter()
+      .map(|s| locate(*s, &almanac.maps))
+      .min().unwrap())
+  }
+
+  fn part2(almanac: &Self::Input) -> Result<Self::Output2, SolveError> {
+    let seeds = &almanac.seeds;
+    let mut seed_ranges =
+      Vec::<std::ops::Range<u64>>::with_capacity(seeds.len());
+    for idx in (0..(seeds.len()-1)).filter(|i| i%2 == 0) {
+      seed_ranges.push(seeds[idx]..(seeds[idx] + seeds[idx+1]));
+    }
+
+    Ok(seed_ranges.iter()
+      .filter_map(|ss| { ss.clone()
+        .into_par_iter()
+        .map(|s| locate(s, &almanac.maps))
+        .min()
+      })
+      .min().unwrap())
+  }
+}
+
+#[test]
+fn example() {
+  let input = Day05::parse(include_str!("../../input/examples/05")).unwrap();
+  assert_eq!(Day05::part1(&input), Ok(35));
+  assert_eq!(Day05::part2(&input), Ok(46));
+}
+
+#[test]
+fn seeds() {
+  let error = |text| Day05::parse(text).unwrap_err().to_string();
+  assert_eq!(error("seeds:\n"), "line 1, column 7: expected seeds in (start, length) pairs, found 0 number(s)");
+  assert_eq!(error("seeds: 5\n"), "line 1, column 8: expected seeds in (start, length) pairs, found 1 number(s)");
+}
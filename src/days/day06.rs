@@ -0,0 +1,100 @@
+use crate::{ParseError, Solution, SolveError};
+use crate::parse::Scanner;
+
+fn count_wins(time: u64, dist: u64) -> u64 {
+    let (min, max) = {
+        let (time, dist) = (time as f64, dist as f64);
+        (((time/2f64) - ((time.powi(2)/4f64) - dist).sqrt()).ceil() as u64,
+         ((time/2f64) + ((time.powi(2)/4f64) - dist).sqrt()).floor() as u64)
+    };
+    // In u128, as the distance can overflow for the long race of part 2.
+    (min..=max).map(|b_time| b_time as u128 * (time - b_time) as u128)
+        .filter(|&b_dist| b_dist > dist as u128)
+        .count() as u64
+}
+
+fn parse_line(
+    line: usize,
+    text: &str,
+    label: &'static str
+) -> Result<Vec<u64>, ParseError> {
+    let mut scanner = Scanner::new(line, text);
+    scanner.literal(label)?;
+    scanner.skip_whitespace();
+    let column = scanner.column();
+    let numbers = scanner.numbers()?;
+    scanner.finish()?;
+    if numbers.is_empty() {
+        return Err(scanner.invalid(column, "expected at least one number".to_string()));
+    }
+    Ok(numbers)
+}
+
+fn part_1((times, dists): &(Vec<u64>, Vec<u64>)) -> u64 {
+    times.iter().zip(dists.iter())
+        .map(|(time, dist)| count_wins(*time, *dist))
+        .product::<u64>()
+}
+
+fn part_2((times, dists): &(Vec<u64>, Vec<u64>)) -> Result<u64, SolveError> {
+    let join = |numbers: &Vec<u64>| {
+        let joined = numbers.iter()
+            .map(|n| n.to_string())
+            .collect::<String>();
+        joined.parse::<u64>()
+            .map_err(|_| SolveError::new(format!("{joined} is too large to race")))
+    };
+
+    Ok(count_wins(join(times)?, join(dists)?))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = (Vec<u64>, Vec<u64>);
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines();
+        let times = parse_line(1, lines.next().unwrap_or(""), "Time:")?;
+        let dists = parse_line(2, lines.next().unwrap_or(""), "Distance:")?;
+        if times.len() != dists.len() {
+            return Err(ParseError::Invalid {
+                line: 2,
+                column: 1,
+                message: format!(
+                    "{} times but {} distances", times.len(), dists.len()
+                ),
+            });
+        }
+        Ok((times, dists))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        Ok(part_1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        part_2(input)
+    }
+}
+
+#[test]
+fn example() {
+    let input = Day06::parse(include_str!("../../input/examples/06")).unwrap();
+    assert_eq!(Day06::part1(&input), Ok(288));
+    assert_eq!(Day06::part2(&input), Ok(71503));
+}
+
+#[test]
+fn races() {
+    let error = Day06::parse("Time:\nDistance:\n").unwrap_err();
+    assert_eq!(error.to_string(), "line 1, column 6: expected at least one number");
+
+    let input = Day06::parse("Time: 1000000000 10000000000\nDistance: 1 2\n").unwrap();
+    assert_eq!(
+        Day06::part2(&input),
+        Err(SolveError::new("100000000010000000000 is too large to race"))
+    );
+}
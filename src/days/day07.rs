@@ -0,0 +1,221 @@
+use crate::{ParseError, Solution, SolveError};
+use crate::parse::Scanner;
+
+// Affects the sorting of card faces and the card type due to the
+// treatment of 'J' as Jack in part 1 and Joker in part 2. Thread local so
+// that tests running in parallel do not change each other's rules.
+thread_local! {
+    static IS_PART_2: std::cell::Cell<bool> = const {std::cell::Cell::new(false)};
+}
+
+type Bid = u32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Card {
+    face: char,
+}
+
+impl Ord for Card {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+
+        let face_ord: [char;13] = if !IS_PART_2.get() {
+            ['A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2']
+        } else {
+            ['A', 'K', 'Q', 'T', '9', '8', '7', '6', '5', '4', '3', '2', 'J']
+        };
+
+        if self.face == other.face {
+            return std::cmp::Ordering::Equal;
+        }
+        for f in face_ord {
+            if f == self.face {
+                return std::cmp::Ordering::Greater;
+            }
+            if f == other.face {
+                return std::cmp::Ordering::Less;
+            }
+        }
+        unreachable!("Invalid card faces: {:?}", (self.face, other.face))
+    }
+}
+
+impl PartialOrd for Card {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+    
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
+enum HandType {High, One, Two, Three, Full, Four, Five}
+    
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
+struct Hand {
+    hand_t: HandType,
+    hand: [Card;5]
+}
+
+
+impl Hand {
+    #[allow(clippy::nonminimal_bool)]
+    fn new(input_hand: &str) -> Hand {
+        
+        let mut hand: [Card;5] = [Card::default(); 5];
+        for (idx, c) in input_hand.chars().enumerate() {
+            hand[idx].face = c;
+        }
+        
+        let mut sorted_hand = hand;
+        sorted_hand.sort_unstable();
+        sorted_hand.reverse();
+            let joker = if !IS_PART_2.get() {Card {face: '\0'}}
+                        else                {Card {face:  'J'}};
+            let hand_t = match sorted_hand {
+                [a,b,c,d,e] if (
+                    a==b && b==c && c==d && d==e     ||
+                    a==b && b==c && c==d && e==joker ||
+                    a==b && b==c && d==joker         ||
+                    a==b && c==joker                 ||
+                    b==joker
+                ) => HandType::Five,
+                
+                [a,b,c,d,e] if (
+                    a==b && b==c && c==d     || b==c && c==d && d==e     ||
+                    a==b && b==c && e==joker || b==c && c==d && e==joker ||
+                    a==b && d==joker         || b==c && d==joker         ||
+                    c==joker 
+                )   => HandType::Four,
+
+                [a,b,c,d,e] if (
+                    a==b && b==c && d==e     ||  a==b && c==d && d==e     ||
+                    a==b && c==d && e==joker
+                ) => HandType::Full,
+
+                [a,b,c,d,e] if (
+                    a==b && b==c     || b==c && c==d     || c==d && d==e     ||
+                    a==b && e==joker || b==c && e==joker || c==d && e==joker ||
+                    d==joker 
+                ) => HandType::Three,
+
+                [a,b,c,d,e] if (
+                    a==b && c==d || a==b && d==e || b==c && d==e
+                ) => HandType::Two,
+
+                [a,b,c,d,e] if (
+                    a==b     || b==c     || c==d     || d==e     ||
+                    e==joker
+                ) => HandType::One,
+
+                _ => HandType::High 
+            };
+
+            Hand {hand_t, hand}
+    }
+}
+
+
+const FACES: &[&str] = &[
+    "A", "K", "Q", "J", "T", "9", "8", "7", "6", "5", "4", "3", "2"
+];
+
+fn parse_round(line: usize, text: &str) -> Result<(String, Bid), ParseError> {
+    let mut scanner = Scanner::new(line, text);
+    let mut hand = String::with_capacity(5);
+    for _ in 0..5 {
+        match scanner.peek() {
+            Some(c) if "AKQJT98765432".contains(c) => hand.push(c),
+            _ => return Err(scanner.unexpected(FACES)),
+        }
+        scanner.next_char();
+    }
+    if !scanner.peek().is_some_and(|c| c.is_whitespace()) {
+        return Err(scanner.unexpected(&["<whitespace>"]));
+    }
+    let bid = scanner.number()?;
+    scanner.finish()?;
+    Ok((hand, bid))
+}
+
+fn evaluate_solution(input: &[(String, Bid)]) -> Bid {
+    let mut rounds: Vec<(Hand, Bid)> = input.iter()
+        .map(|(hand, bid)| (Hand::new(hand), *bid))
+        .collect();
+
+    
+    rounds.sort_unstable_by_key(|r| r.0); // sort by hand
+    
+    rounds.iter()
+        .enumerate()
+        .rev()
+        .map(|(idx,(_, bid))| {
+            bid * (1 + idx as Bid)
+        })
+        .sum()
+}
+
+fn part_1(input: &[(String, Bid)]) -> Bid {
+    IS_PART_2.set(false); // Read comment at declearation for affects.
+    evaluate_solution(input)
+}
+
+fn part_2(input: &[(String, Bid)]) -> Bid {
+    IS_PART_2.set(true);
+    evaluate_solution(input)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<(String, Bid)>;
+    type Output1 = Bid;
+    type Output2 = Bid;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines()
+            .enumerate()
+            .map(|(idx, l)| parse_round(idx + 1, l))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError> {
+        Ok(part_1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError> {
+        Ok(part_2(input))
+    }
+}
+
+
+
+#[test]
+fn hand_types() {
+    assert_eq!(Hand::new("AAAAA").hand_t, HandType::Five);
+    assert_eq!(Hand::new("AA8AA").hand_t, HandType::Four);
+    assert_eq!(Hand::new("23332").hand_t, HandType::Full);
+    assert_eq!(Hand::new("TTT98").hand_t, HandType::Three);
+    assert_eq!(Hand::new("23432").hand_t, HandType::Two);
+    assert_eq!(Hand::new("A23A4").hand_t, HandType::One);
+    assert_eq!(Hand::new("23456").hand_t, HandType::High);
+}
+
+#[test]
+fn hand_order() {
+    assert!(Hand::new("AAAAA") > Hand::new("TTT98"));
+    assert!(Hand::new("A23A4") < Hand::new("AA8AA"));
+
+    assert!(Hand::new("33332") > Hand::new("2AAAA"));
+    assert!(Hand::new("77788") < Hand::new("77888"));
+
+    
+    assert!(Hand::new("JJJJJ") > Hand::new("AJJAA"));
+    IS_PART_2.set(true);
+    assert!(Hand::new("JJJJJ") < Hand::new("AJJAA"));
+}
+
+
+#[test]
+fn example() {
+    let input = Day07::parse(include_str!("../../input/examples/07")).unwrap();
+    assert_eq!(Day07::part1(&input), Ok(6440));
+    assert_eq!(Day07::part2(&input), Ok(5905));
+}
@@ -0,0 +1,264 @@
+use crate::{ParseError, Solution, SolveError};
+use crate::parse::{parse_row, Scanner};
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum NodeReference {
+    Value([char;3]),
+    Index(usize)
+}
+
+impl NodeReference {
+    fn index(self) -> usize {
+        match self {
+            NodeReference::Value(_) => unimplemented!(),
+            NodeReference::Index(i) => i,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Direction {
+    Left,
+    Right
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Node {
+    value: [char;3],
+    left: NodeReference,
+    right: NodeReference
+}
+
+fn get_directions(input: &str) -> Result<Vec<Direction>, ParseError> {
+    let line = input.lines().next().unwrap_or("");
+    let directions = parse_row(1, line, &["L", "R"], |c| match c {
+            'L' => Some(Direction::Left),
+            'R' => Some(Direction::Right),
+            _ => None
+        })?;
+    if directions.is_empty() {
+        return Err(Scanner::new(1, line).unexpected(&["L", "R"]));
+    }
+    Ok(directions)
+}
+
+fn parse_label(scanner: &mut Scanner) -> Result<[char;3], ParseError> {
+    let mut label = ['\0';3];
+    for c in label.iter_mut() {
+        match scanner.peek() {
+            Some(l) if l.is_ascii_alphanumeric() => *c = l,
+            _ => return Err(scanner.unexpected(&["<label>"])),
+        }
+        scanner.next_char();
+    }
+    Ok(label)
+}
+
+fn parse_node(line: usize, text: &str) -> Result<(Node, [usize;2]), ParseError> {
+    let mut scanner = Scanner::new(line, text);
+    let value = parse_label(&mut scanner)?;
+    scanner.literal(" = (")?;
+    let left_col = scanner.column();
+    let left = NodeReference::Value(parse_label(&mut scanner)?);
+    scanner.literal(", ")?;
+    let right_col = scanner.column();
+    let right = NodeReference::Value(parse_label(&mut scanner)?);
+    scanner.literal(")")?;
+    scanner.finish()?;
+
+    Ok((Node {value, left, right}, [left_col, right_col]))
+}
+
+fn get_nodes(input: &str) -> Result<Vec<Node>, ParseError> {
+    let mut lines = input.lines().enumerate().skip(1);
+    if let Some((idx, l)) = lines.next() {
+        Scanner::new(idx + 1, l).finish()?;
+    }
+    let parsed = lines
+        .map(|(idx, l)| parse_node(idx + 1, l).map(|n| (idx + 1, n)))
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    // Part 2's ghosts start from every node ending in A, so there must be one.
+    // AAA and ZZZ are only checked by part 1, as part 2 inputs can lack them.
+    if parsed.is_empty() {
+        return Err(ParseError::Invalid {
+            line: input.lines().count().max(2) + 1,
+            column: 1,
+            message: "expected at least one node".to_string(),
+        });
+    }
+    if !parsed.iter().any(|(_, (n, _))| n.value[2] == 'A') {
+        return Err(ParseError::Invalid {
+            line: parsed[0].0,
+            column: 1,
+            message: "no node ends in A".to_string(),
+        });
+    }
+
+    let mut values = parsed.iter()
+        .map(|(_, (n, _))| n.value)
+        .collect::<Vec<[char;3]>>();
+    values.sort_unstable();
+
+    for (line, (node, columns)) in parsed.iter() {
+        for (reference, column) in [node.left, node.right].iter().zip(columns) {
+            if let NodeReference::Value(v) = reference {
+                if values.binary_search(v).is_err() {
+                    return Err(ParseError::Invalid {
+                        line: *line,
+                        column: *column,
+                        message: format!(
+                            "unknown node {:?}", v.iter().collect::<String>()
+                        ),
+                    });
+                }
+            }
+        }
+    }
+
+    let mut nodes = parsed.into_iter()
+        .map(|(_, (n, _))| n)
+        .collect::<Vec<Node>>();
+
+    nodes.sort_unstable();
+
+    for idx in 0..nodes.len() {
+        let left_idx = match nodes[idx].left {
+            NodeReference::Value(v) =>
+                nodes.binary_search_by_key(&v, |&n| n.value).unwrap(),
+            _ => unreachable!()            
+        };
+        let right_idx = match nodes[idx].right {
+            NodeReference::Value(v) =>
+                nodes.binary_search_by_key(&v, |&n| n.value).unwrap(),
+            _ => unreachable!()            
+        };
+
+        nodes[idx].left = NodeReference::Index(left_idx);
+        nodes[idx].right = NodeReference::Index(right_idx);
+    }
+    Ok(nodes)
+}
+
+// How many steps a walk can take before it must be repeating itself, having
+// been at every node at every position in the directions.
+fn step_limit(nodes: &[Node], directions: &[Direction]) -> u64 {
+    nodes.len() as u64 * directions.len() as u64
+}
+
+fn steps_to_zzz(nodes: &[Node], directions: &[Direction]) -> Result<u64, SolveError> {
+    let find = |label: [char;3]| nodes.binary_search_by_key(&label, |n| n.value);
+    let Ok(start) = find(['A','A','A']) else {
+        return Err(SolveError::new("no node AAA to start from"));
+    };
+    if find(['Z','Z','Z']).is_err() {
+        return Err(SolveError::new("no node ZZZ to reach"));
+    }
+
+    let limit = step_limit(nodes, directions);
+    let mut curr = &nodes[start];
+    let mut count = 0;
+    let mut pos = 0;
+    while curr.value != ['Z','Z','Z'] {
+        if count == limit {
+            return Err(SolveError::new("ZZZ cannot be reached from AAA"));
+        }
+        curr = match directions[pos] {
+            Direction::Left  => &nodes[curr.left.index()],
+            Direction::Right => &nodes[curr.right.index()],
+        };
+        pos = (pos + 1) % directions.len();
+        count += 1;
+    }
+    Ok(count)
+}
+
+fn lcm(a: u64, b:u64) -> Option<u64> {
+    fn gcd(a: u64, b:u64) -> u64 {
+        if b == 0 {return a;}
+        if a == 0 {return b;}
+        if a > b  {gcd(a%b, b)}
+        else      {gcd(a, b%a)}
+    }
+    (a / gcd(a,b)).checked_mul(b)
+}
+
+fn ghost_steps_to_z(nodes: &[Node], directions: &[Direction]) -> Result<u64, SolveError> {
+    let mut curr: Vec<&Node> =
+        nodes.iter().filter(|n| n.value[2] == 'A').collect();
+    let mut periods = Vec::<u64>::with_capacity(curr.len());
+    let limit = step_limit(nodes, directions);
+    let mut count = 0;
+    let mut pos = 0;
+    while !curr.is_empty() {
+        if curr.iter().any(|n| n.value[2] == 'Z') {
+            curr.retain(|n| n.value[2] != 'Z');
+            periods.push(count);
+        }
+        if count == limit && !curr.is_empty() {
+            return Err(SolveError::new(format!(
+                "{} ghost(s) never reach a node ending in Z", curr.len()
+            )));
+        }
+        for idx in 0..curr.len() {
+            curr[idx] = match directions[pos] {
+                Direction::Left  => &nodes[curr[idx].left.index()],
+                Direction::Right => &nodes[curr[idx].right.index()],
+            }
+        }
+        pos = (pos + 1) % directions.len();
+        count += 1;
+    }
+    periods.into_iter()
+        .try_fold(1, lcm)
+        .ok_or_else(|| SolveError::new("number of steps overflows"))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Vec<Direction>, Vec<Node>);
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((get_directions(input)?, get_nodes(input)?))
+    }
+
+    fn part1((directions, nodes): &Self::Input) -> Result<Self::Output1, SolveError> {
+        steps_to_zzz(nodes, directions)
+    }
+
+    fn part2((directions, nodes): &Self::Input) -> Result<Self::Output2, SolveError> {
+        ghost_steps_to_z(nodes, directions)
+    }
+}
+
+#[test]
+fn example() {
+    let input = Day08::parse(include_str!("../../input/examples/08")).unwrap();
+    assert_eq!(Day08::part1(&input), Ok(2));
+
+    let input = Day08::parse(include_str!("../../input/examples/08-2")).unwrap();
+    assert_eq!(Day08::part2(&input), Ok(6));
+}
+
+#[test]
+fn missing_nodes() {
+    let error = Day08::parse("LR\n\n").unwrap_err();
+    assert_eq!(error.to_string(), "line 3, column 1: expected at least one node");
+    let error = Day08::parse("LR\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap_err();
+    assert_eq!(error.to_string(), "line 3, column 1: no node ends in A");
+
+    let input = Day08::parse("L\n\nBBA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+    assert_eq!(Day08::part1(&input), Err(SolveError::new("no node AAA to start from")));
+    assert_eq!(Day08::part2(&input), Ok(1));
+
+    let input = Day08::parse("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+    assert_eq!(Day08::part1(&input), Err(SolveError::new("ZZZ cannot be reached from AAA")));
+    assert_eq!(
+        Day08::part2(&input),
+        Err(SolveError::new("1 ghost(s) never reach a node ending in Z"))
+    );
+}
@@ -0,0 +1,76 @@
+use crate::{ParseError, Solution, SolveError};
+use crate::parse::Scanner;
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    input.lines()
+        .enumerate()
+        .map(|(idx, l)| {
+            let mut scanner = Scanner::new(idx + 1, l);
+            let nums = scanner.numbers::<i32>()?;
+            scanner.finish()?;
+            Ok(nums)
+        })
+        .collect()
+}
+
+fn differences(set: &[i32]) -> Vec<i32> {
+    let mut result = Vec::<i32>::with_capacity(set.len()-1);
+    for idx in 0..set.len()-1 {
+        result.push(set[idx+1] - set[idx]);
+    }
+    result
+
+}
+
+fn sum_next_values(sets: &[Vec<i32>]) -> i32 {
+    fn generate_next_val(set: &[i32]) -> i32 {
+        if set.iter().all(|x| x == &0) {
+            return 0;
+        }
+        set.last().unwrap() + generate_next_val(&differences(set))
+    }
+
+    sets.iter()
+        .map(|s| generate_next_val(s))
+        .sum()
+}
+
+fn sum_prev_values(sets: &[Vec<i32>]) -> i32 {
+    fn generate_prev_val(set: &[i32]) -> i32 {
+        if set.iter().all(|x| x == &0) {
+            return 0;
+        }
+        set[0] - generate_prev_val(&differences(set))
+    }
+
+    sets.iter()
+        .map(|s| generate_prev_val(s))
+        .sum()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i32>>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(sets: &Self::Input) -> Result<Self::Output1, SolveError> {
+        Ok(sum_next_values(sets))
+    }
+
+    fn part2(sets: &Self::Input) -> Result<Self::Output2, SolveError> {
+        Ok(sum_prev_values(sets))
+    }
+}
+
+#[test]
+fn example() {
+    let input = Day09::parse(include_str!("../../input/examples/09")).unwrap();
+    assert_eq!(Day09::part1(&input), Ok(114));
+    assert_eq!(Day09::part2(&input), Ok(2));
+}
@@ -0,0 +1,458 @@
+use crate::{ParseError, Solution, SolveError};
+use crate::geometry::{Direction, Pos};
+use crate::grid::Grid;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Pipe {NS, EW, NE, NW, SE, SW, Clear, Start}
+
+impl std::fmt::Debug for Pipe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Pipe::NS => '│',
+            Pipe::EW => '─',
+            Pipe::NE => '└',
+            Pipe::NW => '┘',
+            Pipe::SE => '┌',
+            Pipe::SW => '┐',
+            Pipe::Clear => '·',
+            Pipe::Start => '#',
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Network {
+    start: Pos,
+    piping: Grid<Pipe>,
+}
+
+impl Network {
+    fn pos(&self, p: &Pos) -> &Pipe {
+        &self.piping[*p]
+    }
+
+    fn mut_pos(&mut self, p: &Pos) -> &mut Pipe {
+        &mut self.piping[*p]
+    }
+
+    // Whether the pipe one step from `from` connects back towards it.
+    fn can_go(&self, from: &Pos, direction: Direction) -> bool {
+        let connecting = match direction {
+            Direction::North => [Pipe::NS, Pipe::SE, Pipe::SW],
+            Direction::South => [Pipe::NS, Pipe::NE, Pipe::NW],
+            Direction::East  => [Pipe::EW, Pipe::NW, Pipe::SW],
+            Direction::West  => [Pipe::EW, Pipe::NE, Pipe::SE],
+            _ => return false,
+        };
+        from.step(direction)
+            .and_then(|p| self.piping.get(p))
+            .is_some_and(|p| connecting.contains(p))
+    }
+
+    fn can_go_north(&self, from: &Pos) -> bool {self.can_go(from, Direction::North)}
+    fn can_go_south(&self, from: &Pos) -> bool {self.can_go(from, Direction::South)}
+    fn can_go_east(&self, from: &Pos)  -> bool {self.can_go(from, Direction::East)}
+    fn can_go_west(&self, from: &Pos)  -> bool {self.can_go(from, Direction::West)}
+}
+
+#[derive(Clone, PartialEq)]
+enum IsClear{Checked(bool), ToCheck, UnChecked}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum InwardDirection{North, South, East, West, None, Unassigned}
+
+fn parse_input(input: &str) -> Result<Network, ParseError> {
+    let piping =
+        Grid::parse(input, &["|", "-", "L", "J", "F", "7", ".", "S"], |p| {
+            match p {
+                '|' => Some(Pipe::NS),
+                '-' => Some(Pipe::EW),
+                'L' => Some(Pipe::NE),
+                'J' => Some(Pipe::NW),
+                'F' => Some(Pipe::SE),
+                '7' => Some(Pipe::SW),
+                '.' => Some(Pipe::Clear),
+                'S' => Some(Pipe::Start),
+                _   => None
+            }
+        })?;
+    let start = piping.positions()
+        .find(|&p| piping[p] == Pipe::Start)
+        .ok_or_else(|| ParseError::Invalid {
+            line: 1,
+            column: 1,
+            message: "no start tile 'S' found".to_string(),
+        })?;
+
+    Ok(Network {start, piping})
+}
+
+fn determine_network(mut n: Network) -> Result<Network, ParseError> {
+    let start = &n.start;
+    let mut possible = Vec::<Pipe>::with_capacity(1);
+
+    if n.can_go_north(start) && n.can_go_south(start) {possible.push(Pipe::NS);}
+    if n.can_go_east(start)  && n.can_go_west(start)  {possible.push(Pipe::EW);}
+    if n.can_go_north(start) && n.can_go_east(start)  {possible.push(Pipe::NE);}
+    if n.can_go_north(start) && n.can_go_west(start)  {possible.push(Pipe::NW);}
+    if n.can_go_south(start) && n.can_go_east(start)  {possible.push(Pipe::SE);}
+    if n.can_go_south(start) && n.can_go_west(start)  {possible.push(Pipe::SW);}
+
+    if possible.len() != 1 {
+        let connecting = Direction::ORTHOGONAL.into_iter()
+            .filter(|&d| n.can_go(start, d))
+            .count();
+        return Err(ParseError::Invalid {
+            line: start.row + 1,
+            column: start.col + 1,
+            message: format!("start tile connects to {connecting} pipes, expected 2"),
+        });
+    }
+
+    let start = n.start;
+    *n.mut_pos(&start) = possible[0];
+    Ok(n)
+}
+
+// Errors, at `curr`, if the loop is broken there or leads off the map.
+fn step_looping_path(network: &Network, curr: &Pos, prev: &Pos) -> Result<Pos, ParseError> {
+    let error = |message: &str| ParseError::Invalid {
+        line: curr.row + 1,
+        column: curr.col + 1,
+        message: message.to_string(),
+    };
+    let direction = if curr == prev {
+        match network.pos(curr) {
+            Pipe::NS => Direction::North,
+            Pipe::EW => Direction::East,
+            Pipe::NE => Direction::North,
+            Pipe::NW => Direction::North,
+            Pipe::SE => Direction::South,
+            Pipe::SW => Direction::South,
+            _ => return Err(error("pipe loop cannot start from this tile")),
+        }
+    } else {
+        // The table below assumes `curr` connects back to `prev`.
+        let connected = Direction::ORTHOGONAL.into_iter()
+            .any(|d| prev.step(d) == Some(*curr) && network.can_go(prev, d));
+        if !connected {
+            return Err(error("pipe loop is broken, tile does not connect to the pipe before it"));
+        }
+        match (network.pos(curr),  network.pos(prev)) {
+            (Pipe::NS, Pipe::NS) => 
+                if prev.step(Direction::North) == Some(*curr) {Direction::North} else {Direction::South},
+            (Pipe::NS, Pipe::NE) => Direction::North,
+            (Pipe::NS, Pipe::NW) => Direction::North,
+            (Pipe::NS, Pipe::SE) => Direction::South,
+            (Pipe::NS, Pipe::SW) => Direction::South,
+            (Pipe::EW, Pipe::EW) =>
+                if prev.step(Direction::East) == Some(*curr) {Direction::East} else {Direction::West},
+            (Pipe::EW, Pipe::NE) => Direction::East,
+            (Pipe::EW, Pipe::NW) => Direction::West,
+            (Pipe::EW, Pipe::SE) => Direction::East,
+            (Pipe::EW, Pipe::SW) => Direction::West,
+            (Pipe::NE, Pipe::NS) => Direction::East,
+            (Pipe::NE, Pipe::EW) => Direction::North,
+            (Pipe::NE, Pipe::NW) => Direction::North,
+            (Pipe::NE, Pipe::SE) => Direction::East,
+            (Pipe::NE, Pipe::SW) =>
+                if prev.step(Direction::South) == Some(*curr) {Direction::East} else {Direction::North},
+            (Pipe::NW, Pipe::NS) => Direction::West,
+            (Pipe::NW, Pipe::EW) => Direction::North,
+            (Pipe::NW, Pipe::NE) => Direction::North,
+            (Pipe::NW, Pipe::SE) =>
+                if prev.step(Direction::East) == Some(*curr) {Direction::North} else {Direction::West},
+            (Pipe::NW, Pipe::SW) => Direction::West,
+            (Pipe::SE, Pipe::NS) => Direction::East,
+            (Pipe::SE, Pipe::EW) => Direction::South,
+            (Pipe::SE, Pipe::NE) => Direction::East,
+            (Pipe::SE, Pipe::NW) =>
+                if prev.step(Direction::West) == Some(*curr) {Direction::South} else {Direction::East},
+            (Pipe::SE, Pipe::SW) => Direction::South,
+            (Pipe::SW, Pipe::NS) => Direction::West,
+            (Pipe::SW, Pipe::EW) => Direction::South,
+            (Pipe::SW, Pipe::NE) =>
+                if prev.step(Direction::North) == Some(*curr) {Direction::West} else {Direction::South},
+            (Pipe::SW, Pipe::NW) => Direction::West,
+            (Pipe::SW, Pipe::SE) => Direction::South,
+            _ => return Err(error("pipe loop is broken, tile does not connect to the pipe before it")),
+        }
+    };
+    curr.step(direction)
+        .filter(|p| network.piping.contains(*p))
+        .ok_or_else(|| error("pipe loop leads off the map"))
+}
+
+
+fn determine_looping_path(network: &Network) -> Result<Vec<Pos>, ParseError> {
+    let mut path = vec![network.start];
+
+    let mut curr = step_looping_path(network, &network.start, &network.start)?;
+    let mut prev = network.start;
+    while curr != network.start {
+        path.push(curr);
+        let next = step_looping_path(network, &curr, &prev)?;
+        prev = curr;
+        curr = next;
+    }
+    Ok(path)
+}
+
+fn remove_unconnected_pipes(mut network: Network, path: &[Pos]) -> Network {
+    let non_path_pipes = network.piping.positions()
+        .filter(|p| !path.contains(p))
+        .collect::<Vec<Pos>>();
+
+    for pos in non_path_pipes {
+        *network.mut_pos(&pos) = Pipe::Clear;
+    }
+    network
+}
+
+// For loops with no NS pipe on their west edge. Scanning each row from the
+// west, every pipe leading north crosses between outside and inside the loop,
+// which must be the only pipes left in `network`.
+fn count_inside_by_crossings(network: &Network) -> usize {
+    network.piping.rows()
+        .map(|row| {
+            let mut inside = false;
+            row.iter()
+                .filter(|&&pipe| {
+                    match pipe {
+                        Pipe::NS | Pipe::NE | Pipe::NW => inside = !inside,
+                        Pipe::Clear => return inside,
+                        _ => (),
+                    }
+                    false
+                })
+                .count()
+        })
+        .sum()
+}
+
+fn count_clear_inside_loop(network: &Network, path: &[Pos]) -> usize {
+
+    // Identify any NS pipe on the edge of the loop.
+    let mut starting_pos: Pos = (usize::MAX,usize::MAX).into();
+
+    'RowsLoop: for r in 0..network.piping.height() {
+        for c in 0..network.piping.width() {
+            match network.pos(&(r,c).into()) {
+                Pipe::NS => {
+                        starting_pos = (r,c).into();
+                        break 'RowsLoop;
+                    },
+                Pipe::Clear => (),
+                _ => continue 'RowsLoop,
+            }
+        }
+    }
+
+    if starting_pos == (usize::MAX,usize::MAX).into() {
+        return count_inside_by_crossings(network);
+    }
+
+    // Assign directions toward inside for all pos in path.
+    let starting_idx = path.iter().position(|pos| pos == &starting_pos)
+    .unwrap_or_else(|| panic!("Failed to find {starting_pos:?} in path:{path:?}"));
+    let mut inside = vec![[InwardDirection::Unassigned; 2]; path.len()];
+    inside[starting_idx] = [InwardDirection::East, InwardDirection::None];
+    
+    type D = InwardDirection;
+
+    let mut curr_idx = (starting_idx + 1) % path.len();
+    let mut prev_idx = starting_idx;
+    while curr_idx != starting_idx {
+        inside[curr_idx] = match (
+            network.pos(&path[curr_idx]),
+            network.pos(&path[prev_idx]),
+            inside[prev_idx]
+        ) {
+            (Pipe::NS, Pipe::NS, d) => d,
+            (Pipe::NE, Pipe::NS, [D::East,  D::None]) => [D::None,  D::None],
+            (Pipe::NE, Pipe::NS, [D::West,  D::None]) => [D::South, D::West],
+            (Pipe::NW, Pipe::NS, [D::East,  D::None]) => [D::South, D::East],
+            (Pipe::NW, Pipe::NS, [D::West,  D::None]) => [D::None,  D::None],
+            (Pipe::SE, Pipe::NS, [D::East,  D::None]) => [D::None,  D::None],
+            (Pipe::SE, Pipe::NS, [D::West,  D::None]) => [D::North, D::West],
+            (Pipe::SW, Pipe::NS, [D::East,  D::None]) => [D::North, D::East],
+            (Pipe::SW, Pipe::NS, [D::West,  D::None]) => [D::None,  D::None],
+            (Pipe::EW, Pipe::EW, d) => d,
+            (Pipe::NE, Pipe::EW, [D::North, D::None]) => [D::None,  D::None],
+            (Pipe::NE, Pipe::EW, [D::South, D::None]) => [D::South, D::West],
+            (Pipe::NW, Pipe::EW, [D::North, D::None]) => [D::None,  D::None],
+            (Pipe::NW, Pipe::EW, [D::South, D::None]) => [D::South, D::East],
+            (Pipe::SE, Pipe::EW, [D::North, D::None]) => [D::North, D::West],
+            (Pipe::SE, Pipe::EW, [D::South, D::None]) => [D::None,  D::None],
+            (Pipe::SW, Pipe::EW, [D::North, D::None]) => [D::North, D::East],
+            (Pipe::SW, Pipe::EW, [D::South, D::None]) => [D::None,  D::None],
+            (Pipe::NS, Pipe::NE, [D::South, D::West]) => [D::West,  D::None],
+            (Pipe::NS, Pipe::NE, [D::None,  D::None]) => [D::East,  D::None],
+            (Pipe::EW, Pipe::NE, [D::South, D::West]) => [D::South, D::None],
+            (Pipe::EW, Pipe::NE, [D::None,  D::None]) => [D::North, D::None],
+            (Pipe::NW, Pipe::NE, [D::South, D::West]) => [D::South, D::East],
+            (Pipe::NW, Pipe::NE, [D::None,  D::None]) => [D::None,  D::None],
+            (Pipe::SE, Pipe::NE, [D::South, D::West]) => [D::North, D::West],
+            (Pipe::SE, Pipe::NE, [D::None,  D::None]) => [D::None,  D::None],
+            (Pipe::SW, Pipe::NE, [D::South, D::West]) => [D::None,  D::None],
+            (Pipe::SW, Pipe::NE, [D::None,  D::None]) => [D::North, D::East],
+            (Pipe::NS, Pipe::NW, [D::South, D::East]) => [D::East,  D::None],
+            (Pipe::NS, Pipe::NW, [D::None,  D::None]) => [D::West,  D::None],
+            (Pipe::EW, Pipe::NW, [D::South, D::East]) => [D::South, D::None],
+            (Pipe::EW, Pipe::NW, [D::None,  D::None]) => [D::North, D::None],
+            (Pipe::NE, Pipe::NW, [D::South, D::East]) => [D::South, D::West],
+            (Pipe::NE, Pipe::NW, [D::None,  D::None]) => [D::None,  D::None],
+            (Pipe::SE, Pipe::NW, [D::South, D::East]) => [D::None,  D::None],
+            (Pipe::SE, Pipe::NW, [D::None,  D::None]) => [D::North, D::West],
+            (Pipe::SW, Pipe::NW, [D::South, D::East]) => [D::North, D::East],
+            (Pipe::SW, Pipe::NW, [D::None,  D::None]) => [D::None,  D::None],
+            (Pipe::NS, Pipe::SE, [D::North, D::West]) => [D::West,  D::None],
+            (Pipe::NS, Pipe::SE, [D::None,  D::None]) => [D::East,  D::None],
+            (Pipe::EW, Pipe::SE, [D::North, D::West]) => [D::North, D::None],
+            (Pipe::EW, Pipe::SE, [D::None,  D::None]) => [D::South, D::None],
+            (Pipe::NE, Pipe::SE, [D::North, D::West]) => [D::South, D::West],
+            (Pipe::NE, Pipe::SE, [D::None,  D::None]) => [D::None,  D::None],
+            (Pipe::NW, Pipe::SE, [D::North, D::West]) => [D::None,  D::None],
+            (Pipe::NW, Pipe::SE, [D::None,  D::None]) => [D::South, D::East],
+            (Pipe::SW, Pipe::SE, [D::North, D::West]) => [D::North, D::East],
+            (Pipe::SW, Pipe::SE, [D::None,  D::None]) => [D::None,  D::None],
+            (Pipe::NS, Pipe::SW, [D::North, D::East]) => [D::East,  D::None],
+            (Pipe::NS, Pipe::SW, [D::None,  D::None]) => [D::West,  D::None],
+            (Pipe::EW, Pipe::SW, [D::North, D::East]) => [D::North, D::None],
+            (Pipe::EW, Pipe::SW, [D::None,  D::None]) => [D::South, D::None],
+            (Pipe::NE, Pipe::SW, [D::North, D::East]) => [D::None,  D::None],
+            (Pipe::NE, Pipe::SW, [D::None,  D::None]) => [D::South, D::West],
+            (Pipe::NW, Pipe::SW, [D::North, D::East]) => [D::South, D::East],
+            (Pipe::NW, Pipe::SW, [D::None,  D::None]) => [D::None,  D::None],
+            (Pipe::SE, Pipe::SW, [D::North, D::East]) => [D::North, D::West],
+            (Pipe::SE, Pipe::SW, [D::None,  D::None]) => [D::None,  D::None],
+            (a, b, c) => unreachable!("Unexpected position: {:?}",(a,b,c))
+        };
+        prev_idx = curr_idx;
+        curr_idx = (curr_idx + 1) % path.len();
+    }
+
+
+    // Assign all Pipe::Clears pointed to by inside[] to IsClear::toCheck
+    let inside_clears_pos = path.iter()
+        .zip(inside)
+        .flat_map(|(pos,direct_arr)| [(pos,direct_arr[0]),(pos, direct_arr[1])])
+        .filter(|(_, direction)| direction != &InwardDirection::None)
+        .filter_map(|(pos, direction)| match direction {
+            InwardDirection::North => pos.step(Direction::North),
+            InwardDirection::South => pos.step(Direction::South),
+            InwardDirection::East  => pos.step(Direction::East),
+            InwardDirection::West  => pos.step(Direction::West),
+            d => unreachable!("Unexpected direction {:?} at {:?}", d, pos),
+        })
+        .filter(|pos| network.piping.get(*pos) == Some(&Pipe::Clear));
+
+    // Assign all Pipe::Clears poined to by inside[] to IsClear::toCheck
+    let mut inside_clears = Grid::filled(
+        network.piping.width(),
+        network.piping.height(),
+        IsClear::UnChecked
+    );
+
+    for pos in inside_clears_pos {
+        inside_clears[pos] = IsClear::ToCheck;
+    }
+
+    // Check all IsClear::toCheck and flood fill all remaining Pipe::Clear
+    while inside_clears.iter().any(|x| x == &IsClear::ToCheck) {
+        let to_check: Vec<Pos> = // list of clears to check ranges for
+            inside_clears.positions()
+                .filter(|&p| inside_clears[p] == IsClear::ToCheck)
+                .collect();
+
+        for p in to_check.iter() { // setting ToCheck to Checked
+            inside_clears[*p] = IsClear::Checked(true);
+        }
+
+        let to_check_range: Vec<Pos> = to_check.iter() // list ToCheck ranges
+            .flat_map(|p| inside_clears.neighbours(*p))
+            .filter(|(_, clear)| clear == &&IsClear::UnChecked)
+            .map(|(p, _)| p)
+            .collect();
+
+        for p in to_check_range { // checking ToCheck ranges
+            if network.pos(&p) == &Pipe::Clear {
+                inside_clears[p] = IsClear::ToCheck;
+            } else {
+                inside_clears[p] = IsClear::Checked(false);
+            }
+        }
+    }
+
+    inside_clears.iter()
+        .filter(|x| x == &&IsClear::Checked(true))
+        .count()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = (Network, Vec<Pos>);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let network = determine_network(parse_input(input)?)?;
+        let path = determine_looping_path(&network)?;
+        Ok((network, path))
+    }
+
+    fn part1((_, path): &Self::Input) -> Result<Self::Output1, SolveError> {
+        Ok(path.len() / 2)
+    }
+
+    fn part2((network, path): &Self::Input) -> Result<Self::Output2, SolveError> {
+        let network = remove_unconnected_pipes(network.clone(), path);
+
+        Ok(count_clear_inside_loop(&network, path))
+    }
+}
+
+#[test]
+fn example() {
+    let input = Day10::parse(include_str!("../../input/examples/10")).unwrap();
+    assert_eq!(Day10::part1(&input), Ok(4));
+
+    let input = Day10::parse(include_str!("../../input/examples/10-3")).unwrap();
+    assert_eq!(Day10::part1(&input), Ok(8));
+
+    let input = Day10::parse(include_str!("../../input/examples/10-2")).unwrap();
+    assert_eq!(Day10::part2(&input), Ok(8));
+}
+
+#[test]
+fn broken_loops() {
+    let error = |input| Day10::parse(input).unwrap_err().to_string();
+    assert_eq!(
+        error(".....\n.S-7.\n.|...\n.L-J.\n.....\n"),
+        "line 3, column 4: pipe loop is broken, tile does not connect to the pipe before it"
+    );
+    assert_eq!(
+        error(".....\n.S-7.\n.|.|.\n.L-|.\n.....\n"),
+        "line 4, column 4: pipe loop is broken, tile does not connect to the pipe before it"
+    );
+    assert_eq!(error(".S.\n...\n"), "line 1, column 2: start tile connects to 0 pipes, expected 2");
+    assert_eq!(error("-S-\n.|.\n"), "line 1, column 2: start tile connects to 3 pipes, expected 2");
+}
+
+#[test]
+fn loops_without_ns_edges() {
+    let input = Day10::parse("S7\nLJ\n").unwrap();
+    assert_eq!(Day10::part2(&input), Ok(0));
+
+    let input = Day10::parse("\
+..S7..
+.FJL7.
+FJ..L7
+L7..FJ
+.L7FJ.
+..LJ..
+").unwrap();
+    assert_eq!(Day10::part1(&input), Ok(10));
+    assert_eq!(Day10::part2(&input), Ok(4));
+}
@@ -0,0 +1,107 @@
+use itertools::iproduct;
+use crate::{ParseError, Solution, SolveError};
+use crate::geometry::Pos;
+use crate::grid::Grid;
+
+#[derive(PartialEq, Clone)]
+pub enum Pixel {
+    Nothing,
+    Galaxy
+}
+
+impl std::fmt::Debug for Pixel  {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Galaxy => write!(f, "#"),
+            Self::Nothing => write!(f, "·"),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<Pixel>, ParseError> {
+    Grid::parse(input, &[".", "#"], |c| match c {
+        '.' => Some(Pixel::Nothing),
+        '#' => Some(Pixel::Galaxy),
+        _   => None
+    })
+}
+
+fn expand_space(
+    space: &Grid<Pixel>,
+    mut galaxies: Vec<Pos>,
+    expansion_size: usize
+) -> Vec<Pos> {
+
+    let clear_cols = (0..space.width())
+        .filter(|c| space.column(*c).all(|p| p == &Pixel::Nothing))
+        .collect::<Vec<usize>>();
+
+    let clear_rows = space.rows()
+        .enumerate()
+        .filter(|(_, row)| row.iter().all(|p| p == &Pixel::Nothing))
+        .map(|(r, _)| r)
+        .collect::<Vec<usize>>();
+
+    for galaxy in galaxies.iter_mut() {
+        *galaxy = Pos::new(
+            galaxy.row + expansion_size *
+                clear_rows.iter().filter(|r| r < &&galaxy.row).count(),
+            galaxy.col + expansion_size *
+                clear_cols.iter().filter(|c| c < &&galaxy.col).count()
+        );
+    }
+
+    galaxies
+}
+
+fn locate_galaxies(space: &Grid<Pixel>) -> Vec<Pos> {
+    space.positions()
+        .filter(|&p| space[p] == Pixel::Galaxy)
+        .collect()
+}
+
+fn sum_shortest_distatnces(galx: &[Pos]) -> usize {
+    let mut sum = 0;
+    let pairs = iproduct!(0..galx.len(), 0..galx.len())
+        .filter(|(i, j)| i < j);
+
+    for (i, j) in pairs {
+        sum += galx[i].manhattan(galx[j]);
+    }
+
+    sum
+}
+
+fn solve(space: &Grid<Pixel>, expansion_size: usize) -> usize {
+    let galaxies = locate_galaxies(space);
+    let galaxies = expand_space(space, galaxies, expansion_size);
+
+    sum_shortest_distatnces(&galaxies)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<Pixel>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(space: &Self::Input) -> Result<Self::Output1, SolveError> {
+        Ok(solve(space, 2-1))
+    }
+
+    fn part2(space: &Self::Input) -> Result<Self::Output2, SolveError> {
+        Ok(solve(space, 1000000-1))
+    }
+}
+
+#[test]
+fn example() {
+    let input = Day11::parse(include_str!("../../input/examples/11")).unwrap();
+    assert_eq!(Day11::part1(&input), Ok(374));
+    assert_eq!(Day11::part2(&input), Ok(82000210));
+}
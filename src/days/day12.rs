@@ -0,0 +1,199 @@
+use std::collections::{HashMap, VecDeque};
+use crate::{ParseError, Solution, SolveError};
+use crate::parse::Scanner;
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown
+}
+
+impl std::fmt::Debug for Spring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Operational => write!(f, "·"),
+            Self::Damaged => write!(f, "#"),
+            Self::Unknown => write!(f, "?"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Record {
+    springs: VecDeque<Spring>,
+    damaged: VecDeque<u32>
+}
+
+fn parse_record(line: usize, text: &str) -> Result<Record, ParseError> {
+    let mut scanner = Scanner::new(line, text);
+    let mut springs = VecDeque::new();
+    loop {
+        springs.push_back(match scanner.peek() {
+            Some('.') => Spring::Operational,
+            Some('#') => Spring::Damaged,
+            Some('?') => Spring::Unknown,
+            Some(' ') if !springs.is_empty() => break,
+            _ => return Err(scanner.unexpected(&[".", "#", "?"])),
+        });
+        scanner.next_char();
+    }
+
+    let mut damaged = VecDeque::new();
+    loop {
+        scanner.skip_whitespace();
+        let column = scanner.column();
+        let count = scanner.number()?;
+        if count == 0 {
+            return Err(scanner.invalid(column, "damaged groups cannot be empty".to_string()));
+        }
+        damaged.push_back(count);
+        if scanner.peek() != Some(',') {
+            break;
+        }
+        scanner.next_char();
+    }
+    scanner.finish()?;
+
+    Ok(Record {springs, damaged})
+}
+
+fn parse_input(input: &str) -> Result<Vec<Record>, ParseError> {
+    input.lines()
+        .enumerate()
+        .map(|(idx, l)| parse_record(idx + 1, l))
+        .collect()
+}
+
+fn quintuple_records(mut record_vec: Vec<Record>) ->  Vec<Record> {
+    let mut temp_springs: VecDeque<Spring> = VecDeque::new();
+    let mut temp_damaged: VecDeque<u32> = VecDeque::new();
+    for record in record_vec.iter_mut() {
+        for _ in 0..4 {
+            temp_springs.push_back(Spring::Unknown);
+            temp_springs.append(&mut record.springs.clone());
+            temp_damaged.append(&mut record.damaged.clone());
+        }
+        record.springs.append(&mut temp_springs);
+        record.damaged.append(&mut temp_damaged);
+    }
+    record_vec
+}
+
+fn count_permutations(record: Record, memo: &mut HashMap::<Record,u64>) -> u64 {
+    if let Some(&result) = memo.get(&record) {
+        return result;
+    }
+
+    if record.damaged.is_empty() && record.springs.is_empty() {
+        memo.insert(record, 1);
+        return 1;
+    }
+
+    if record.damaged.is_empty() &&
+        record.springs.iter().any(|s| s == &Spring::Damaged) {
+        memo.insert(record, 0);
+        return 0;
+    }
+
+    if record.springs.len() < (
+        (record.damaged.iter().sum::<u32>() as usize +
+        record.damaged.len()).saturating_sub(1)
+    ) {
+        memo.insert(record, 0);
+        return 0;
+    }
+
+    let step_damaged_count = {
+        let mut result = 0;
+        if !record.damaged.is_empty() {
+            let mut new_record = record.clone();
+            let damaged_count= new_record.damaged.pop_front().unwrap() as usize;
+            let mut failed_to_step_damaged = false;
+
+            for idx in 0..damaged_count {
+                if record.springs[idx] == Spring::Operational {
+                    failed_to_step_damaged = true;
+                    break;
+                }
+            }
+
+            for _ in 0..damaged_count {new_record.springs.pop_front();}
+            if !new_record.damaged.is_empty() {
+                if let Some(Spring::Damaged) = new_record.springs.pop_front() {
+                        failed_to_step_damaged = true;
+                    }
+            }
+
+            if !failed_to_step_damaged {
+                result = count_permutations(new_record.clone(), memo);
+                memo.insert(new_record, result);
+            }
+        }
+        result
+    };
+
+    let skip_spring_count = {
+        let mut result = 0;
+        if record.springs[0] != Spring::Damaged {
+            let mut new_record = record.clone();
+            new_record.springs.pop_front();
+
+            result = count_permutations(new_record.clone(), memo);
+            memo.insert(new_record, result);
+        }
+        result
+    };
+
+    let result = step_damaged_count + skip_spring_count;
+    memo.insert(record, result);
+    result
+}
+
+fn count_all_permutations(
+    records: &[Record],
+    memo: &mut HashMap::<Record,u64>
+) -> u64 {
+    records.iter()
+        .map(|record| count_permutations(record.clone(), memo))
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(record_vec: &Self::Input) -> Result<Self::Output1, SolveError> {
+        let mut memo = HashMap::<Record,u64>::new();
+
+        Ok(count_all_permutations(record_vec, &mut memo))
+    }
+
+    fn part2(record_vec: &Self::Input) -> Result<Self::Output2, SolveError> {
+        let record_vec = quintuple_records(record_vec.clone());
+        let mut memo = HashMap::<Record,u64>::new();
+
+        Ok(count_all_permutations(&record_vec, &mut memo))
+    }
+}
+
+#[test]
+fn example() {
+    let input = Day12::parse(include_str!("../../input/examples/12")).unwrap();
+    assert_eq!(Day12::part1(&input), Ok(21));
+    assert_eq!(Day12::part2(&input), Ok(525152));
+}
+
+#[test]
+fn empty_groups() {
+    let error = Day12::parse("???.### 1,1,3\n?.# 1,0\n").unwrap_err();
+    assert_eq!(error.to_string(), "line 2, column 7: damaged groups cannot be empty");
+    assert!(Day12::parse("? 0").is_err());
+}
@@ -0,0 +1,123 @@
+use crate::{ParseError, Solution, SolveError};
+use crate::grid::Grid;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Terrain {
+    Ash,
+    Rock
+}
+
+impl std::fmt::Debug for Terrain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ash => write!(f, "·"),
+            Self::Rock => write!(f, "#"),
+        }
+    }
+}
+
+
+pub struct Pattern {
+    pattern: Grid<Terrain>
+}
+
+impl Pattern {
+    fn rotate_clone(&self) -> Pattern {
+        Pattern {pattern: self.pattern.transpose()}
+    }
+
+    fn is_mirror(&self, idx: usize, tolerance: u32) -> bool {
+        let mut curr = idx;
+        let mut reflection = curr+1;
+        let mut difference = 0;
+        while curr < self.pattern.height() && reflection < self.pattern.height() {
+            difference += self.pattern.row(curr).iter()
+                .zip(self.pattern.row(reflection))
+                .filter(|(a, b)| a != b)
+                .count() as u32;
+            if difference > tolerance {return false;}
+            curr = curr.overflowing_sub(1).0;
+            reflection += 1;
+        }
+        difference == tolerance
+    }
+
+    fn locate_mirror(&self, tolerance: u32) -> usize {
+        for idx in 0..self.pattern.height().saturating_sub(1) {
+            if self.is_mirror(idx, tolerance)  {
+                return idx + 1;
+            }
+        }
+        0
+    }
+
+    fn _debug_print(&self) {
+        for row in self.pattern.rows() {
+            for terrain_elem in row {
+                print!("{:?}", terrain_elem);
+            }
+            println!();
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    let mut patterns = vec![];
+    let mut lines = input.lines().enumerate().peekable();
+    while let Some(&(first_idx, _)) = lines.peek() {
+        let block = lines.by_ref()
+            .map(|(_, l)| l)
+            .take_while(|l| !l.is_empty())
+            .collect::<Vec<&str>>();
+        if block.is_empty() {continue;}
+
+        let pattern = Grid::parse_from_line(
+            first_idx + 1,
+            &block.join("\n"),
+            &[".", "#"],
+            |t| match t {
+                '.' => Some(Terrain::Ash),
+                '#' => Some(Terrain::Rock),
+                _   => None,
+            }
+        )?;
+        patterns.push(Pattern {pattern});
+    }
+    Ok(patterns)
+}
+
+fn summarise_patterns(patterns: &[Pattern], tolerance: u32) -> usize {
+    patterns.iter()
+        .zip(patterns.iter().map(|p| p.rotate_clone()))
+        .map(|(p, pr)|
+            p.locate_mirror(tolerance) * 100 + pr.locate_mirror(tolerance)
+        )
+        .sum()       
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Pattern>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(patterns: &Self::Input) -> Result<Self::Output1, SolveError> {
+        Ok(summarise_patterns(patterns, 0))
+    }
+
+    fn part2(patterns: &Self::Input) -> Result<Self::Output2, SolveError> {
+        Ok(summarise_patterns(patterns, 1))
+    }
+}
+
+#[test]
+fn example() {
+    let input = Day13::parse(include_str!("../../input/examples/13")).unwrap();
+    assert_eq!(Day13::part1(&input), Ok(405));
+    assert_eq!(Day13::part2(&input), Ok(400));
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use crate::{ParseError, Solution, SolveError};
+use crate::grid::Grid;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tile {
+    Nothing,
+    Cube,
+    Sphere,
+}
+
+impl std::fmt::Debug for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Nothing => write!(f, "·"),
+            Self::Cube => write!(f, "#"),
+            Self::Sphere => write!(f, "O"),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::parse(input, &[".", "#", "O"], |c| match c {
+        '.' => Some(Tile::Nothing),
+        '#' => Some(Tile::Cube),
+        'O' => Some(Tile::Sphere),
+        _   => None
+    })
+}
+
+fn east_tilt(dish: &Grid<Tile>) -> Grid<Tile> {
+    let mut result = dish.clone();
+    for row in 0..result.height() {
+        for segment in result.row_mut(row).split_mut(|t| t == &Tile::Cube) {
+            let nothing_count =
+                segment.iter().filter(|t| t == &&Tile::Nothing).count();
+
+            segment[..nothing_count].fill(Tile::Nothing);
+            segment[nothing_count..].fill(Tile::Sphere);
+        }
+    }
+    result
+}
+
+fn east_load_count(dish: &Grid<Tile>) -> usize {
+    let mut load = 0;
+    for row in dish.rows() {
+        for (idx, tile) in row.iter().enumerate() {
+            if tile == &Tile::Sphere { load = load + idx + 1 }
+        }
+    }
+    load
+}
+
+fn rotate_90_clone(dish: &Grid<Tile>) -> Grid<Tile> {
+    dish.rotate_clockwise()
+}
+
+fn north_tilt(dish: &Grid<Tile>) -> Grid<Tile> {
+    let mut result = rotate_90_clone(dish);
+        result = east_tilt(&result);
+    for _ in 0..3 {
+        result = rotate_90_clone(&result);
+    }
+    result
+}
+
+fn north_load_count(dish: &Grid<Tile>) -> usize {
+    east_load_count(&rotate_90_clone(dish))
+}
+
+
+fn tilt_cycle(dish: &Grid<Tile>) -> Grid<Tile> {
+    let mut dish = dish.clone();
+    for _ in 0..4 {
+        dish = rotate_90_clone(&dish);
+        dish = east_tilt(&dish);
+    }
+    dish
+}
+
+fn tilt_cycle_rep(
+    dish: &Grid<Tile>,
+    num_cycles: u64,
+    memo_step: u64
+) -> Grid<Tile> {
+    assert!(num_cycles.is_multiple_of(memo_step));
+
+    let mut dish = dish.clone();
+    let mut memo = HashMap::<Grid<Tile>, Grid<Tile>>::new();
+
+    for _ in 0..(num_cycles/memo_step) {
+        if let Some(d) = memo.get(&dish) {
+            dish = d.clone();
+        } else {
+            let mut altered = tilt_cycle(&dish);
+            for _ in 0..(memo_step-1) {altered = tilt_cycle(&altered)}
+            memo.insert(dish, altered.clone());
+            dish = altered;
+        }
+    }
+    dish
+}
+fn _print_dish(dish: &Grid<Tile>) {
+    for row in dish.rows() {
+        for tile in row {
+            print!("{:?}", tile);
+        }
+        println!();
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid<Tile>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(dish: &Self::Input) -> Result<Self::Output1, SolveError> {
+        Ok(north_load_count(&north_tilt(dish)))
+    }
+
+    fn part2(dish: &Self::Input) -> Result<Self::Output2, SolveError> {
+        Ok(north_load_count(&tilt_cycle_rep(dish, 1000000000, 1000)))
+    }
+}
+
+#[test]
+fn example() {
+    let input = Day14::parse(include_str!("../../input/examples/14")).unwrap();
+    assert_eq!(Day14::part1(&input), Ok(136));
+    assert_eq!(Day14::part2(&input), Ok(64));
+}
@@ -0,0 +1,133 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use crate::{ParseError, Solution, SolveError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: u32,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub parse_elapsed: Duration,
+    pub answers: Vec<Answer>,
+}
+
+// Why an input has no answers: it failed to parse, or parsed but `part`
+// could not be solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    Solve {part: u32, error: SolveError},
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse(error) => write!(f, "{error}"),
+            Error::Solve {part, error} => write!(f, "part {part}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub number: u32,
+    solve: fn(&str, &[u32]) -> Result<Solved, Error>,
+}
+
+impl Day {
+    // Parses the input once and returns the answers to `parts` in order,
+    // timing the parse and each part separately.
+    pub fn solve(&self, input: &str, parts: &[u32]) -> Result<Solved, Error> {
+        (self.solve)(input, parts)
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[u32]) -> Result<Solved, Error> {
+    solve_with(input, parts, S::parse, S::part1, S::part2)
+}
+
+// Like `Day::solve`, for solvers that need more than a `Solution` impl, such
+// as days with options chosen on the command line.
+pub fn solve_with<I, A: Display, B: Display>(
+    input: &str,
+    parts: &[u32],
+    parse: impl FnOnce(&str) -> Result<I, ParseError>,
+    part1: impl Fn(&I) -> Result<A, SolveError>,
+    part2: impl Fn(&I) -> Result<B, SolveError>,
+) -> Result<Solved, Error> {
+    let start = Instant::now();
+    let input = parse(input)?;
+    let parse_elapsed = start.elapsed();
+
+    let answers = parts.iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => part1(&input).map(|a| a.to_string()),
+                2 => part2(&input).map(|a| a.to_string()),
+                p => panic!("Invalid part: {p}"),
+            };
+            let answer = answer.map_err(|error| Error::Solve {part, error})?;
+            Ok(Answer {part, answer, elapsed: start.elapsed()})
+        })
+        .collect::<Result<Vec<Answer>, Error>>()?;
+
+    Ok(Solved {parse_elapsed, answers})
+}
+
+macro_rules! register_days {
+    ($($number:literal => $solution:ty),* $(,)?) => {
+        pub const DAYS: &[Day] = &[$(
+            Day {number: $number, solve: solve::<$solution>},
+        )*];
+    };
+}
+
+register_days! {
+    1  => day01::Day01,
+    2  => day02::Day02,
+    3  => day03::Day03,
+    4  => day04::Day04,
+    5  => day05::Day05,
+    6  => day06::Day06,
+    7  => day07::Day07,
+    8  => day08::Day08,
+    9  => day09::Day09,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+}
+
+pub fn get(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
@@ -0,0 +1,134 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    // Change in (row, col) for one step, with north being up.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::North     => (-1,  0),
+            Direction::NorthEast => (-1,  1),
+            Direction::East      => ( 0,  1),
+            Direction::SouthEast => ( 1,  1),
+            Direction::South     => ( 1,  0),
+            Direction::SouthWest => ( 1, -1),
+            Direction::West      => ( 0, -1),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::North     => Direction::South,
+            Direction::NorthEast => Direction::SouthWest,
+            Direction::East      => Direction::West,
+            Direction::SouthEast => Direction::NorthWest,
+            Direction::South     => Direction::North,
+            Direction::SouthWest => Direction::NorthEast,
+            Direction::West      => Direction::East,
+            Direction::NorthWest => Direction::SouthEast,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from((row, col): (usize, usize)) -> Self {
+        Pos {row, col}
+    }
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Pos {row, col}
+    }
+
+    // `None` if the step would leave the non-negative quadrant.
+    pub fn step(self, direction: Direction) -> Option<Pos> {
+        let (dr, dc) = direction.offset();
+        Some(Pos {
+            row: self.row.checked_add_signed(dr)?,
+            col: self.col.checked_add_signed(dc)?,
+        })
+    }
+
+    // Steps on a `width` by `height` torus, so leaving one edge re-enters at
+    // the opposite one.
+    pub fn step_wrapping(
+        self,
+        direction: Direction,
+        width: usize,
+        height: usize
+    ) -> Pos {
+        let (dr, dc) = direction.offset();
+        Pos {
+            row: (self.row as isize + dr).rem_euclid(height as isize) as usize,
+            col: (self.col as isize + dc).rem_euclid(width as isize) as usize,
+        }
+    }
+
+    pub fn neighbours(self) -> impl Iterator<Item = Pos> {
+        Direction::ORTHOGONAL.into_iter().filter_map(move |d| self.step(d))
+    }
+
+    // Neighbours including diagonals.
+    pub fn adjacent(self) -> impl Iterator<Item = Pos> {
+        Direction::ALL.into_iter().filter_map(move |d| self.step(d))
+    }
+
+    pub fn wrapping_neighbours(
+        self,
+        width: usize,
+        height: usize
+    ) -> impl Iterator<Item = Pos> {
+        Direction::ORTHOGONAL.into_iter()
+            .map(move |d| self.step_wrapping(d, width, height))
+    }
+
+    pub fn manhattan(self, other: Pos) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+#[test]
+fn edges() {
+    let origin = Pos::new(0, 0);
+    assert_eq!(origin.step(Direction::North), None);
+    assert_eq!(origin.step(Direction::SouthEast), Some(Pos::new(1, 1)));
+    assert_eq!(origin.neighbours().collect::<Vec<Pos>>(), [Pos::new(0, 1), Pos::new(1, 0)]);
+    assert_eq!(origin.adjacent().count(), 3);
+    assert_eq!(origin.step_wrapping(Direction::NorthWest, 5, 3), Pos::new(2, 4));
+    assert_eq!(origin.wrapping_neighbours(5, 3).count(), 4);
+    assert_eq!(Pos::new(2, 7).manhattan(Pos::new(5, 1)), 9);
+}
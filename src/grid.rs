@@ -0,0 +1,227 @@
+use crate::ParseError;
+use crate::geometry::Pos;
+use crate::parse::parse_row;
+
+// A rectangular grid stored row by row in a single `Vec`, indexed by `Pos`
+// or a `(row, col)` pair.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "Grid size does not match cells.");
+        Grid {width, height, cells}
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where T: Clone {
+        Grid {width, height, cells: vec![value; width * height]}
+    }
+
+    // Returns the index of the first row whose width differs from the first.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, usize> {
+        let width = rows.first().map_or(0, |r| r.len());
+        let height = rows.len();
+        if let Some(idx) = rows.iter().position(|r| r.len() != width) {
+            return Err(idx);
+        }
+        Ok(Grid {width, height, cells: rows.into_iter().flatten().collect()})
+    }
+
+    // Parses a char map, mapping each char with `f`, where the first line of
+    // `input` is line `first_line` of the file.
+    pub fn parse_from_line(
+        first_line: usize,
+        input: &str,
+        expected: &[&'static str],
+        f: impl Fn(char) -> Option<T>
+    ) -> Result<Self, ParseError> {
+        let rows = input.lines()
+            .enumerate()
+            .map(|(idx, l)| parse_row(first_line + idx, l, expected, &f))
+            .collect::<Result<Vec<Vec<T>>, ParseError>>()?;
+
+        let width = rows.first().map_or(0, |r| r.len());
+        Grid::from_rows(rows).map_err(|idx| ParseError::Invalid {
+            line: first_line + idx,
+            column: 1,
+            message: format!(
+                "row has width {}, expected {width}",
+                input.lines().nth(idx).unwrap().chars().count()
+            ),
+        })
+    }
+
+    pub fn parse(
+        input: &str,
+        expected: &[&'static str],
+        f: impl Fn(char) -> Option<T>
+    ) -> Result<Self, ParseError> {
+        Grid::parse_from_line(1, input, expected, f)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: impl Into<Pos>) -> bool {
+        let pos = pos.into();
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: impl Into<Pos>) -> Option<&T> {
+        let pos = pos.into();
+        match self.contains(pos) {
+            true  => Some(&self.cells[pos.row * self.width + pos.col]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: impl Into<Pos>) -> Option<&mut T> {
+        let pos = pos.into();
+        match self.contains(pos) {
+            true  => Some(&mut self.cells[pos.row * self.width + pos.col]),
+            false => None,
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|r| self.row(r))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "Column {col} out of bounds.");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|c| self.column(c))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    // Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height * width).map(move |idx| Pos::new(idx / width, idx % width))
+    }
+
+    // The in-bounds cells north, east, south and west of `pos`.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        pos.neighbours().filter_map(|p| self.get(p).map(|t| (p, t)))
+    }
+
+    // The in-bounds cells surrounding `pos`, including diagonals.
+    pub fn adjacent(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        pos.adjacent().filter_map(|p| self.get(p).map(|t| (p, t)))
+    }
+
+    // Orthogonal neighbours, treating opposite edges as connected.
+    pub fn wrapping_neighbours(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        pos.wrapping_neighbours(self.width, self.height)
+            .map(|p| (p, &self[p]))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    fn from_fn(
+        width: usize,
+        height: usize,
+        f: impl Fn(usize, usize) -> T
+    ) -> Grid<T> {
+        let cells = (0..width * height)
+            .map(|idx| f(idx / width, idx % width))
+            .collect();
+        Grid {width, height, cells}
+    }
+
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |r, c| self[(c, r)].clone())
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let height = self.height;
+        Grid::from_fn(height, self.width, |r, c| self[(height - 1 - c, r)].clone())
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Grid<T> {
+        let width = self.width;
+        Grid::from_fn(self.height, width, |r, c| self[(c, width - 1 - r)].clone())
+    }
+
+    // Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let width = self.width;
+        Grid::from_fn(width, self.height, |r, c| self[(r, width - 1 - c)].clone())
+    }
+
+    // Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let height = self.height;
+        Grid::from_fn(self.width, height, |r, c| self[(height - 1 - r, c)].clone())
+    }
+}
+
+impl<T, P: Into<Pos>> std::ops::Index<P> for Grid<T> {
+    type Output = T;
+    fn index(&self, pos: P) -> &Self::Output {
+        let pos = pos.into();
+        self.get(pos).unwrap_or_else(|| panic!("Position {pos:?} out of bounds."))
+    }
+}
+
+impl<T, P: Into<Pos>> std::ops::IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, pos: P) -> &mut Self::Output {
+        let pos = pos.into();
+        self.get_mut(pos).unwrap_or_else(|| panic!("Position {pos:?} out of bounds."))
+    }
+}
+
+#[test]
+fn transformations() {
+    let grid = Grid::parse("abc\ndef\n", &[], Some).unwrap();
+    let text = |g: &Grid<char>| g.rows()
+        .map(|r| r.iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join("/");
+
+    assert_eq!(text(&grid.transpose()), "ad/be/cf");
+    assert_eq!(text(&grid.rotate_clockwise()), "da/eb/fc");
+    assert_eq!(text(&grid.rotate_counter_clockwise()), "cf/be/ad");
+    assert_eq!(text(&grid.flip_horizontal()), "cba/fed");
+    assert_eq!(text(&grid.flip_vertical()), "def/abc");
+    assert_eq!(grid.column(1).collect::<String>(), "be");
+    assert_eq!(grid.neighbours(Pos::new(0, 0)).map(|(_, c)| *c).collect::<String>(), "bd");
+    assert_eq!(grid.adjacent(Pos::new(1, 2)).map(|(_, c)| *c).collect::<String>(), "ceb");
+    assert_eq!(
+        grid.wrapping_neighbours(Pos::new(0, 0)).map(|(_, c)| *c).collect::<String>(),
+        "dbdc"
+    );
+    assert!(Grid::parse("ab\nc\n", &[], Some).is_err());
+}
@@ -0,0 +1,10 @@
+pub mod answers;
+pub mod cli;
+pub mod days;
+pub mod geometry;
+pub mod grid;
+pub mod parse;
+mod solution;
+
+pub use parse::ParseError;
+pub use solution::{Solution, SolveError};
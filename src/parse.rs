@@ -0,0 +1,218 @@
+use std::str::FromStr;
+
+// Lines and columns are 1-based and columns count chars, not bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    // `found` is `None` when the line ended before a token was complete.
+    UnexpectedChar {
+        line: usize,
+        column: usize,
+        found: Option<char>,
+        expected: Vec<&'static str>,
+    },
+    UnexpectedToken {
+        line: usize,
+        column: usize,
+        found: String,
+        expected: Vec<&'static str>,
+    },
+    Invalid {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+}
+
+impl ParseError {
+    pub fn line(&self) -> usize {
+        match self {
+            ParseError::UnexpectedChar {line, ..}  => *line,
+            ParseError::UnexpectedToken {line, ..} => *line,
+            ParseError::Invalid {line, ..}         => *line,
+        }
+    }
+
+    pub fn column(&self) -> usize {
+        match self {
+            ParseError::UnexpectedChar {column, ..}  => *column,
+            ParseError::UnexpectedToken {column, ..} => *column,
+            ParseError::Invalid {column, ..}         => *column,
+        }
+    }
+}
+
+fn write_expected(
+    f: &mut std::fmt::Formatter<'_>,
+    expected: &[&str]
+) -> std::fmt::Result {
+    // Token classes such as "<number>" are written as is, literals are quoted.
+    let tokens = expected.iter()
+        .map(|t| if t.starts_with('<') {t.to_string()} else {format!("'{t}'")})
+        .collect::<Vec<String>>();
+    match tokens.len() {
+        0 => Ok(()),
+        1 => write!(f, ", expected {}", tokens[0]),
+        _ => write!(f, ", expected one of {}", tokens.join(", ")),
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: ", self.line(), self.column())?;
+        match self {
+            ParseError::UnexpectedChar {found: Some(c), expected, ..} => {
+                write!(f, "unexpected {c:?}")?;
+                write_expected(f, expected)
+            },
+            ParseError::UnexpectedChar {found: None, expected, ..} => {
+                write!(f, "unexpected end of line")?;
+                write_expected(f, expected)
+            },
+            ParseError::UnexpectedToken {found, expected, ..} => {
+                write!(f, "unexpected {found:?}")?;
+                write_expected(f, expected)
+            },
+            ParseError::Invalid {message, ..} => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Steps through a single line of input, tracking the column for errors.
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    line: usize,
+    text: &'a str,
+    offset: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(line: usize, text: &'a str) -> Self {
+        Scanner {line, text, offset: 0}
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.text[..self.offset].chars().count() + 1
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.offset..]
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offset == self.text.len()
+    }
+
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace()) {
+            self.next_char();
+        }
+    }
+
+    pub fn unexpected(&self, expected: &[&'static str]) -> ParseError {
+        ParseError::UnexpectedChar {
+            line: self.line,
+            column: self.column(),
+            found: self.peek(),
+            expected: expected.to_vec(),
+        }
+    }
+
+    pub fn invalid(&self, column: usize, message: String) -> ParseError {
+        ParseError::Invalid {line: self.line, column, message}
+    }
+
+    // Consumes `token` exactly, reporting the first char that differs.
+    pub fn literal(&mut self, token: &'static str) -> Result<(), ParseError> {
+        let start = self.offset;
+        for expected in token.chars() {
+            if self.peek() != Some(expected) {
+                let error = self.unexpected(&[token]);
+                self.offset = start;
+                return Err(error);
+            }
+            self.next_char();
+        }
+        Ok(())
+    }
+
+    // Consumes chars while `pred` holds and returns them.
+    pub fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+        let start = self.offset;
+        while self.peek().is_some_and(&pred) {
+            self.next_char();
+        }
+        &self.text[start..self.offset]
+    }
+
+    // Consumes an optionally signed decimal number, after any whitespace.
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_whitespace();
+        let start = self.offset;
+        let column = self.column();
+        if self.peek() == Some('-') {
+            self.next_char();
+        }
+        if !self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.offset = start;
+            return Err(self.unexpected(&["<number>"]));
+        }
+        self.take_while(|c| c.is_ascii_digit());
+
+        let text = &self.text[start..self.offset];
+        text.parse::<T>()
+            .map_err(|_| self.invalid(column, format!("invalid number {text:?}")))
+    }
+
+    // Consumes whitespace separated numbers until anything else is reached.
+    pub fn numbers<T: FromStr>(&mut self) -> Result<Vec<T>, ParseError> {
+        let mut numbers = vec![];
+        self.skip_whitespace();
+        while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '-') {
+            numbers.push(self.number()?);
+            self.skip_whitespace();
+        }
+        Ok(numbers)
+    }
+
+    pub fn finish(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        match self.is_empty() {
+            true  => Ok(()),
+            false => Err(self.unexpected(&[])),
+        }
+    }
+}
+
+// Parses one line of a char map, mapping each char with `f`.
+pub fn parse_row<T>(
+    line: usize,
+    text: &str,
+    expected: &[&'static str],
+    f: impl Fn(char) -> Option<T>
+) -> Result<Vec<T>, ParseError> {
+    text.chars()
+        .enumerate()
+        .map(|(idx, c)| f(c).ok_or_else(|| ParseError::UnexpectedChar {
+            line,
+            column: idx + 1,
+            found: Some(c),
+            expected: expected.to_vec(),
+        }))
+        .collect()
+}
@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+use crate::ParseError;
+
+// Why a part has no answer for an input that parsed, e.g. because the answer
+// overflows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        SolveError {message: message.into()}
+    }
+}
+
+impl std::fmt::Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+pub trait Solution {
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Self::Output1, SolveError>;
+    fn part2(input: &Self::Input) -> Result<Self::Output2, SolveError>;
+}